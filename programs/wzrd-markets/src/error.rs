@@ -193,4 +193,17 @@ pub enum MarketsError {
     // ─── Audit Phase 4 Low fixes ───────────────────────────────────────────────
     #[msg("resolve_deadline_slot is too far in the future (exceeds MAX_MARKET_DURATION_SLOTS from now).")]
     DeadlineTooFar = 52,
+
+    // ─── Post-audit additions ──────────────────────────────────────────────────
+    #[msg("creator_fee_bps exceeds MAX_CREATOR_FEE_BPS.")]
+    CreatorFeeTooHigh = 53,
+
+    #[msg("Market has no accrued creator fees to claim.")]
+    NoCreatorFeesAccrued = 54,
+
+    #[msg("Token program does not own the mint it is paired with.")]
+    TokenProgramMismatch = 55,
+
+    #[msg("Market still holds unclaimed creator fees; claim them before closing.")]
+    CreatorFeesUnclaimed = 56,
 }
@@ -32,6 +32,7 @@ pub struct MarketCreated {
     pub resolution_root_seq: u64,
     pub resolve_deadline_slot: u64,
     pub slot: u64,
+    pub creator_fee_bps: u16,
 }
 
 /// Emitted by `initialize_pool` (Phase 2). The constant-product YES/NO pool over
@@ -152,6 +153,8 @@ pub struct PublisherAllowlistChanged {
 /// Emitted by `publish_attention_root` (Phase 3). One per resolution window. The
 /// off-chain builder (cross-repo contract, conventions v1) is expected to read
 /// `window_id` + `merkle_root` + `leaf_count` back from this event / account.
+/// `dataset_hash` is the publisher's commitment to the tree's input dataset;
+/// appended last so decoders of the original layout still read a valid prefix.
 #[event]
 pub struct AttentionRootPublished {
     pub window_id: u64,
@@ -161,6 +164,7 @@ pub struct AttentionRootPublished {
     pub seq: u64,
     pub publisher: Pubkey,
     pub published_at_slot: u64,
+    pub dataset_hash: [u8; 32],
 }
 
 /// Emitted by `resolve_market` (Phase 3). The outcome is encoded per
@@ -189,8 +193,10 @@ pub struct DisputeWindowExtended {
 }
 
 /// Emitted by `settle` (Phase 3). `winner` is the winning outcome (0=NO, 1=YES);
-/// `amount` winning-outcome tokens were burned and `amount` USDC paid to the
-/// settler — the lockstep that preserves `vault >= winning_supply` (audit MR-1).
+/// `amount` winning-outcome tokens were burned and `amount` USDC left the vault
+/// — the lockstep that preserves `vault >= winning_supply` (audit MR-1). Of that,
+/// `creator_fee` stayed in the vault as accrued creator fees and the rest went to
+/// the settler. `creator_fee` is appended last so the original layout is a prefix.
 #[event]
 pub struct Settled {
     pub market: Pubkey,
@@ -198,6 +204,19 @@ pub struct Settled {
     pub winner: u8,
     pub amount: u64,
     pub settler: Pubkey,
+    pub creator_fee: u64,
+}
+
+/// Emitted by `claim_creator_fees` (Phase 3). `amount` accrued creator fees left
+/// the vault for `recipient`; the market's accrued balance is now zero.
+#[event]
+pub struct CreatorFeesClaimed {
+    pub market: Pubkey,
+    pub market_id: u64,
+    pub creator: Pubkey,
+    pub amount: u64,
+    pub recipient: Pubkey,
+    pub slot: u64,
 }
 
 /// Emitted by `resolve_override` (Phase 3). The multisig corrected a contested
@@ -235,6 +254,17 @@ pub struct MarketClosed {
     pub slot: u64,
 }
 
+/// Emitted by `cancel_market`. A market with no positions was cancelled and
+/// the Market + vault rent returned.
+#[event]
+pub struct MarketCancelled {
+    pub market: Pubkey,
+    pub market_id: u64,
+    pub cancelled_by: Pubkey,
+    pub rent_recipient: Pubkey,
+    pub slot: u64,
+}
+
 /// Emitted by `set_admin` (audit C-02 step 1). The current admin proposed a new
 /// admin; not effective until `accept_admin`. `pending_admin == Pubkey::default()`
 /// means a pending rotation was cancelled.
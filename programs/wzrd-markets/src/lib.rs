@@ -145,6 +145,11 @@ pub const INVALID_RECOVERY_GRACE_SLOTS: u64 = 216_000;
 /// rather than the ~90-day value implied by this constant alone.
 pub const MAX_MARKET_DURATION_SLOTS: u64 = 19_440_000;
 
+/// Maximum `creator_fee_bps` a market may be created with (10%). The fee is
+/// taken out of each winning settler's payout, so an uncapped fee would let a
+/// creator confiscate the winners' collateral.
+pub const MAX_CREATOR_FEE_BPS: u16 = 1_000;
+
 // ─── settle_unlock helpers (L-03) ─────────────────────────────────────────────
 // Three callers compute settle_unlock_slot with the same checked_add pattern but
 // from different bases: `resolve_market` anchors from NOW, `extend_dispute_window`
@@ -342,12 +347,17 @@ pub mod wzrd_markets {
     /// market opens. The caller-supplied `market_id` must equal
     /// `config.next_market_id` (sequential, gap-free → collision-free PDA seed).
     ///
+    /// `creator_fee_bps` is the trailing arg added post-audit; it changes the IX
+    /// data, so clients built against the original layout must append it (`0`
+    /// keeps the old no-fee behavior). See `docs/cpmm-phase1-scope.md`.
+    ///
     /// Preconditions:
     ///   - signer == config.admin (Unauthorized otherwise).
     ///   - market_id == config.next_market_id (InvalidMarketId otherwise).
     ///   - metric is a defined MarketMetric (InvalidMetric otherwise).
     ///   - resolution_root != [0; 32] (ZeroResolutionRoot otherwise).
     ///   - resolve_deadline_slot > current slot (DeadlineInPast otherwise).
+    ///   - creator_fee_bps <= MAX_CREATOR_FEE_BPS (CreatorFeeTooHigh otherwise).
     ///   - Market PDA does not already exist (the `init` constraint enforces).
     ///
     /// Postconditions:
@@ -364,6 +374,7 @@ pub mod wzrd_markets {
         resolution_root_seq: u64,
         resolve_deadline_slot: u64,
         dispute_window_slots: u64,
+        creator_fee_bps: u16,
     ) -> Result<()> {
         let clock_slot = Clock::get()?.slot;
 
@@ -402,6 +413,10 @@ pub mod wzrd_markets {
                 && dispute_window_slots <= MAX_DISPUTE_WINDOW_SLOTS,
             MarketsError::InvalidDisputeWindow
         );
+        require!(
+            creator_fee_bps <= MAX_CREATOR_FEE_BPS,
+            MarketsError::CreatorFeeTooHigh
+        );
 
         let creator = ctx.accounts.admin.key();
         let market_key = ctx.accounts.market.key();
@@ -437,7 +452,10 @@ pub mod wzrd_markets {
         market.no_mint = Pubkey::default();
         market.vault = Pubkey::default();
         market.tokens_initialized = false;
-        market._reserved = [0u8; 47];
+        market.creator_fee_bps = creator_fee_bps;
+        market.creator_fees_accrued = 0;
+        market.creator_fees_paid = 0;
+        market._reserved = [0u8; 29];
 
         // Advance the monotonic counter for the next market.
         let config = &mut ctx.accounts.config;
@@ -457,6 +475,7 @@ pub mod wzrd_markets {
             resolution_root_seq,
             resolve_deadline_slot,
             slot: clock_slot,
+            creator_fee_bps,
         });
         Ok(())
     }
@@ -1190,6 +1209,18 @@ pub mod wzrd_markets {
         Ok(())
     }
 
+    /// Phase 2 — read-only view of the market's implied YES probability (bps).
+    ///
+    /// Complete sets keep YES and NO supply equal, so the supply ratio carries no
+    /// signal; the odds come from the pool. Same model as the `Swapped` event:
+    /// price(YES) = `no_reserve * 10_000 / (yes_reserve + no_reserve)` over the
+    /// REAL reserves. An empty pool returns 5000 (even odds). The value is
+    /// returned as Anchor return data, so clients read it by simulating the IX.
+    pub fn market_odds(ctx: Context<MarketOdds>) -> Result<u16> {
+        let pool = &ctx.accounts.pool;
+        Ok(implied_yes_price_bps(pool.yes_reserve, pool.no_reserve))
+    }
+
     // ─── Phase 3 — resolution + settlement ────────────────────────────────────
     // In-house publisher (audit H-02 option (b)), create-time finality snapshot
     // (H-01), ONE keccak convention (M-04/CH-3), MR-1 solvency through settle.
@@ -1335,10 +1366,14 @@ pub mod wzrd_markets {
     ///
     /// Mirrors rails `publish_listen_payout_root` (H-01-hardened). The allow-listed
     /// publisher commits `merkle_root` + `leaf_count` + `schema_version` for
-    /// `window_id`. One root per window: the per-window `AttentionRoot` PDA is
-    /// `init`-ed, so re-publishing the same `window_id` fails on the account-already-
-    /// exists constraint (`WindowAlreadyPublished` surfaced via the typed pre-check
-    /// is not needed — Anchor's `init` is the gate; we keep the error variant for
+    /// `window_id`, plus a `dataset_hash` over the input dataset the tree was built
+    /// from (stored verbatim, not interpreted — it exists so a disputed root can be
+    /// tied back to the exact off-chain sample set).
+    ///
+    /// One root per window: the per-window `AttentionRoot` PDA is `init`-ed, so
+    /// re-publishing the same `window_id` fails on the account-already-exists
+    /// constraint (`WindowAlreadyPublished` surfaced via the typed pre-check is
+    /// not needed — Anchor's `init` is the gate; we keep the error variant for
     /// the SDK's benefit).
     ///
     /// FINALITY (H-01): this account is for discoverability + the `leaf_count`
@@ -1354,6 +1389,7 @@ pub mod wzrd_markets {
         ctx: Context<PublishAttentionRoot>,
         window_id: u64,
         merkle_root: [u8; 32],
+        dataset_hash: [u8; 32],
         leaf_count: u32,
         schema_version: u8,
     ) -> Result<()> {
@@ -1388,7 +1424,7 @@ pub mod wzrd_markets {
         root.schema_version = schema_version;
         root.published_at_slot = slot;
         root.publisher = publisher;
-        root._reserved = [0u8; 32];
+        root.dataset_hash = dataset_hash;
 
         emit!(AttentionRootPublished {
             window_id,
@@ -1398,6 +1434,7 @@ pub mod wzrd_markets {
             seq,
             publisher,
             published_at_slot: slot,
+            dataset_hash,
         });
         Ok(())
     }
@@ -1602,6 +1639,13 @@ pub mod wzrd_markets {
     /// INVALID markets refuse here (`MarketInvalidUseRedeem`) — both sides recover
     /// via `redeem_complete_set`.
     ///
+    /// CREATOR FEE: when `market.creator_fee_bps > 0`, `floor(amount * bps /
+    /// 10_000)` of the payout stays in the vault and accrues to
+    /// `market.creator_fees_accrued`; the settler receives the remainder. The
+    /// creator pulls it later via `claim_creator_fees`, so settle never depends
+    /// on a creator-owned account. Solvency becomes
+    /// `vault >= winning_supply + creator_fees_accrued`.
+    ///
     /// Preconditions:
     ///   - market.resolved (MarketNotResolved).
     ///   - clock.slot >= settle_unlock_slot (DisputeWindowOpen).
@@ -1618,6 +1662,7 @@ pub mod wzrd_markets {
         let settle_unlock_slot = ctx.accounts.market.settle_unlock_slot;
         let market_id = ctx.accounts.market.market_id;
         let market_bump = ctx.accounts.market.bump;
+        let creator_fee_bps = ctx.accounts.market.creator_fee_bps;
         let usdc_decimals = ctx.accounts.usdc_mint.decimals;
 
         require!(resolved, MarketsError::MarketNotResolved);
@@ -1662,6 +1707,10 @@ pub mod wzrd_markets {
             settler_winning_balance >= amount,
             MarketsError::InsufficientOutcomeBalance
         );
+        let creator_fee = compute_creator_fee(amount, creator_fee_bps)?;
+        let settler_payout = amount
+            .checked_sub(creator_fee)
+            .ok_or(MarketsError::MathOverflow)?;
 
         // Burn `amount` winning-outcome tokens from the caller (they sign).
         token_interface::burn(
@@ -1676,40 +1725,174 @@ pub mod wzrd_markets {
             amount,
         )?;
 
-        // Transfer `amount` USDC vault → settler; the Market PDA signs.
+        // Transfer the settler's share out of the vault; the creator's fee stays
+        // behind for `claim_creator_fees`. The Market PDA signs.
         let market_id_bytes = market_id.to_le_bytes();
         let market_seeds: &[&[u8]] = &[MARKET_SEED, market_id_bytes.as_ref(), &[market_bump]];
         let signer_seeds: &[&[&[u8]]] = &[market_seeds];
 
+        if settler_payout > 0 {
+            token_interface::transfer_checked(
+                CpiContext::new_with_signer(
+                    ctx.accounts.usdc_token_program.to_account_info(),
+                    TransferChecked {
+                        from: ctx.accounts.vault.to_account_info(),
+                        mint: ctx.accounts.usdc_mint.to_account_info(),
+                        to: ctx.accounts.settler_usdc.to_account_info(),
+                        authority: ctx.accounts.market.to_account_info(),
+                    },
+                    signer_seeds,
+                ),
+                settler_payout,
+                usdc_decimals,
+            )?;
+        }
+
+        // Accounting: track cumulative settled winning supply (for sweep/close and
+        // off-chain reconciliation).
+        let market = &mut ctx.accounts.market;
+        market.settled_supply = market
+            .settled_supply
+            .checked_add(amount)
+            .ok_or(MarketsError::MathOverflow)?;
+        market.creator_fees_accrued = market
+            .creator_fees_accrued
+            .checked_add(creator_fee)
+            .ok_or(MarketsError::MathOverflow)?;
+        market.creator_fees_paid = market
+            .creator_fees_paid
+            .checked_add(creator_fee)
+            .ok_or(MarketsError::MathOverflow)?;
+
+        emit!(Settled {
+            market: market.key(),
+            market_id,
+            winner: outcome,
+            amount,
+            settler: ctx.accounts.settler.key(),
+            creator_fee,
+        });
+        Ok(())
+    }
+
+    /// Phase 3 — read-only view of the creator's lifetime fee revenue.
+    ///
+    /// Returns `market.creator_fees_paid`: the running total of creator fees
+    /// `settle` has credited to the creator. It never decreases — a claim does
+    /// not reset it. The still-unclaimed balance is `market.creator_fees_accrued`.
+    /// Returned as Anchor return data, so clients read it by simulating the IX.
+    pub fn creator_revenue(ctx: Context<CreatorRevenue>) -> Result<u64> {
+        Ok(ctx.accounts.market.creator_fees_paid)
+    }
+
+    /// Phase 3 — pay the market's accrued creator fees out of the vault.
+    ///
+    /// Transfers the whole `market.creator_fees_accrued` balance to a USDC
+    /// account of the creator's choosing and zeroes the counter. The Market PDA
+    /// signs. Only the creator's own fees move: the winners' collateral
+    /// (`vault - creator_fees_accrued`) is untouched.
+    ///
+    /// Preconditions:
+    ///   - signer is `market.creator` (Unauthorized).
+    ///   - creator_fees_accrued > 0 (NoCreatorFeesAccrued).
+    pub fn claim_creator_fees(ctx: Context<ClaimCreatorFees>) -> Result<()> {
+        require_keys_eq!(
+            ctx.accounts.creator.key(),
+            ctx.accounts.market.creator,
+            MarketsError::Unauthorized
+        );
+        let amount = ctx.accounts.market.creator_fees_accrued;
+        require!(amount > 0, MarketsError::NoCreatorFeesAccrued);
+
+        let market_id = ctx.accounts.market.market_id;
+        let market_id_bytes = market_id.to_le_bytes();
+        let market_seeds: &[&[u8]] = &[
+            MARKET_SEED,
+            market_id_bytes.as_ref(),
+            &[ctx.accounts.market.bump],
+        ];
         token_interface::transfer_checked(
             CpiContext::new_with_signer(
                 ctx.accounts.usdc_token_program.to_account_info(),
                 TransferChecked {
                     from: ctx.accounts.vault.to_account_info(),
                     mint: ctx.accounts.usdc_mint.to_account_info(),
-                    to: ctx.accounts.settler_usdc.to_account_info(),
+                    to: ctx.accounts.creator_usdc.to_account_info(),
                     authority: ctx.accounts.market.to_account_info(),
                 },
-                signer_seeds,
+                &[market_seeds],
             ),
             amount,
-            usdc_decimals,
+            ctx.accounts.usdc_mint.decimals,
         )?;
 
-        // Accounting: track cumulative settled winning supply (for sweep/close and
-        // off-chain reconciliation).
         let market = &mut ctx.accounts.market;
-        market.settled_supply = market
-            .settled_supply
-            .checked_add(amount)
-            .ok_or(MarketsError::MathOverflow)?;
+        market.creator_fees_accrued = 0;
 
-        emit!(Settled {
+        emit!(CreatorFeesClaimed {
             market: market.key(),
             market_id,
-            winner: outcome,
+            creator: ctx.accounts.creator.key(),
             amount,
-            settler: ctx.accounts.settler.key(),
+            recipient: ctx.accounts.creator_usdc.key(),
+            slot: Clock::get()?.slot,
+        });
+        Ok(())
+    }
+
+    /// Phase 3 — pay an absent creator's accrued fees to their USDC ATA (admin).
+    ///
+    /// `close_market` refuses a market with unclaimed creator fees
+    /// (`CreatorFeesUnclaimed`), so a creator who never claims would pin the
+    /// market open. This admin fallback moves the whole `creator_fees_accrued`
+    /// balance to the creator's canonical USDC ATA (never an admin-chosen
+    /// account) and zeroes the counter, exactly as `claim_creator_fees` would.
+    ///
+    /// Preconditions:
+    ///   - signer is `config.admin` (Unauthorized).
+    ///   - creator_fees_accrued > 0 (NoCreatorFeesAccrued).
+    ///   - the creator's USDC ATA exists (anyone can create it idempotently).
+    pub fn push_creator_fees(ctx: Context<PushCreatorFees>) -> Result<()> {
+        require_keys_eq!(
+            ctx.accounts.admin.key(),
+            ctx.accounts.config.admin,
+            MarketsError::Unauthorized
+        );
+        let amount = ctx.accounts.market.creator_fees_accrued;
+        require!(amount > 0, MarketsError::NoCreatorFeesAccrued);
+
+        let market_id = ctx.accounts.market.market_id;
+        let market_id_bytes = market_id.to_le_bytes();
+        let market_seeds: &[&[u8]] = &[
+            MARKET_SEED,
+            market_id_bytes.as_ref(),
+            &[ctx.accounts.market.bump],
+        ];
+        token_interface::transfer_checked(
+            CpiContext::new_with_signer(
+                ctx.accounts.usdc_token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.vault.to_account_info(),
+                    mint: ctx.accounts.usdc_mint.to_account_info(),
+                    to: ctx.accounts.creator_usdc.to_account_info(),
+                    authority: ctx.accounts.market.to_account_info(),
+                },
+                &[market_seeds],
+            ),
+            amount,
+            ctx.accounts.usdc_mint.decimals,
+        )?;
+
+        let market = &mut ctx.accounts.market;
+        market.creator_fees_accrued = 0;
+
+        emit!(CreatorFeesClaimed {
+            market: market.key(),
+            market_id,
+            creator: market.creator,
+            amount,
+            recipient: ctx.accounts.creator_usdc.key(),
+            slot: Clock::get()?.slot,
         });
         Ok(())
     }
@@ -1795,7 +1978,8 @@ pub mod wzrd_markets {
     /// After everyone has settled (binary: the winning supply is 0) or redeemed
     /// (INVALID: both supplies are 0), any rounding dust left in the vault is swept
     /// to the admin-chosen recipient. Guard: the relevant supply MUST be 0, so a
-    /// market with live redemption obligations cannot be swept.
+    /// market with live redemption obligations cannot be swept. Unclaimed
+    /// creator fees (`market.creator_fees_accrued`) are not dust and stay behind.
     pub fn sweep_residual(ctx: Context<SweepResidual>) -> Result<()> {
         require_keys_eq!(
             ctx.accounts.admin.key(),
@@ -1828,7 +2012,11 @@ pub mod wzrd_markets {
             require!(winning_supply == 0, MarketsError::SupplyNotZero);
         }
 
-        let dust = ctx.accounts.vault.amount;
+        let dust = ctx
+            .accounts
+            .vault
+            .amount
+            .saturating_sub(market.creator_fees_accrued);
         if dust > 0 {
             let market_id_bytes = market.market_id.to_le_bytes();
             let market_bump = market.bump;
@@ -1890,6 +2078,12 @@ pub mod wzrd_markets {
                 clock_slot.saturating_sub(market.settle_unlock_slot) > INVALID_RECOVERY_GRACE_SLOTS;
             require!(past_grace, MarketsError::SupplyNotZero);
         }
+        // The creator's accrued fees must be paid out first (claim_creator_fees,
+        // or push_creator_fees for an absent creator) — they are not dust.
+        require!(
+            market.creator_fees_accrued == 0,
+            MarketsError::CreatorFeesUnclaimed
+        );
         // Vault must be drained to dust (rounding residue only).
         let dust = ctx.accounts.vault.amount;
         require!(dust <= MARKET_CLOSE_DUST_THRESHOLD, MarketsError::VaultNotDrained);
@@ -1933,6 +2127,99 @@ pub mod wzrd_markets {
         });
         Ok(())
     }
+
+    /// Cancel a market nobody ever took a position in (admin).
+    ///
+    /// Reclaims the rent of the `Market` account and, once tokens are initialized,
+    /// its USDC vault. Any USDC in the vault (donations, dust) has no claimant
+    /// while both supplies are 0, so it is transferred to the admin-chosen
+    /// `recipient` (as `sweep_residual` does) before the vault closes — never
+    /// burned. A market whose tokens were never initialized passes no mints,
+    /// vault or recipient.
+    ///
+    /// LIMITATION: the YES/NO mints are NOT reclaimed. `initialize_market_tokens`
+    /// creates them without the Token-2022 `MintCloseAuthority` extension, so the
+    /// token program cannot close them and their rent stays locked. With zero
+    /// supply and a closed Market they are inert.
+    ///
+    /// Preconditions:
+    ///   - signer is `config.admin`
+    ///   - market not resolved and no Pool initialized (PoolAlreadyExists)
+    ///   - if tokens are initialized: YES and NO supply are both 0, and a
+    ///     `recipient` is passed when the vault holds any USDC
+    pub fn cancel_market(ctx: Context<CancelMarket>) -> Result<()> {
+        require_keys_eq!(
+            ctx.accounts.admin.key(),
+            ctx.accounts.config.admin,
+            MarketsError::Unauthorized
+        );
+        let market = &ctx.accounts.market;
+        require!(!market.resolved, MarketsError::MarketResolved);
+        // A Pool holds rent in its PDA, LP mint and reserve ATAs that no close
+        // path reclaims yet; refuse rather than strand it behind a closed Market.
+        require!(
+            ctx.accounts.pool.data_is_empty(),
+            MarketsError::PoolAlreadyExists
+        );
+
+        if market.tokens_initialized {
+            let (Some(yes_mint), Some(no_mint), Some(vault)) = (
+                ctx.accounts.yes_mint.as_ref(),
+                ctx.accounts.no_mint.as_ref(),
+                ctx.accounts.vault.as_ref(),
+            ) else {
+                return err!(MarketsError::AccountMismatch);
+            };
+            require!(
+                yes_mint.supply == 0 && no_mint.supply == 0,
+                MarketsError::SupplyNotZero
+            );
+
+            // Move any USDC out + close the vault while the Market PDA still
+            // exists to sign; Anchor's `close = rent_recipient` on the Market runs
+            // after the handler (same ordering as close_market's L-02 fix).
+            let market_id_bytes = market.market_id.to_le_bytes();
+            let market_seeds: &[&[u8]] = &[MARKET_SEED, market_id_bytes.as_ref(), &[market.bump]];
+            let residual = vault.amount;
+            if residual > 0 {
+                let Some(recipient) = ctx.accounts.recipient.as_ref() else {
+                    return err!(MarketsError::AccountMismatch);
+                };
+                token_interface::transfer_checked(
+                    CpiContext::new_with_signer(
+                        ctx.accounts.usdc_token_program.to_account_info(),
+                        TransferChecked {
+                            from: vault.to_account_info(),
+                            mint: ctx.accounts.usdc_mint.to_account_info(),
+                            to: recipient.to_account_info(),
+                            authority: ctx.accounts.market.to_account_info(),
+                        },
+                        &[market_seeds],
+                    ),
+                    residual,
+                    ctx.accounts.usdc_mint.decimals,
+                )?;
+            }
+            token_interface::close_account(CpiContext::new_with_signer(
+                ctx.accounts.usdc_token_program.to_account_info(),
+                CloseAccount {
+                    account: vault.to_account_info(),
+                    destination: ctx.accounts.rent_recipient.to_account_info(),
+                    authority: ctx.accounts.market.to_account_info(),
+                },
+                &[market_seeds],
+            ))?;
+        }
+
+        emit!(MarketCancelled {
+            market: ctx.accounts.market.key(),
+            market_id: ctx.accounts.market.market_id,
+            cancelled_by: ctx.accounts.admin.key(),
+            rent_recipient: ctx.accounts.rent_recipient.key(),
+            slot: Clock::get()?.slot,
+        });
+        Ok(())
+    }
 }
 
 // ─── Phase 2 pure helpers ─────────────────────────────────────────────────────
@@ -2068,6 +2355,33 @@ fn implied_no_price_bps(yes_reserve: u64, no_reserve: u64) -> u64 {
     u64::try_from(bps).unwrap_or(10_000)
 }
 
+/// Implied price of YES in basis points over the REAL reserves (the mirror of
+/// `implied_no_price_bps`): `no_reserve * 10_000 / (yes_reserve + no_reserve)`.
+/// Returns 5000 when the pool is empty, so a fresh market reads as even odds.
+#[inline(never)]
+fn implied_yes_price_bps(yes_reserve: u64, no_reserve: u64) -> u16 {
+    let total = (yes_reserve as u128) + (no_reserve as u128);
+    if total == 0 {
+        return 5_000;
+    }
+    let bps = (no_reserve as u128) * 10_000u128 / total;
+    u16::try_from(bps).unwrap_or(10_000)
+}
+
+/// Creator's share of a `settle` payout: `floor(amount * creator_fee_bps /
+/// 10_000)`. Floor rounding keeps the settler whole on dust; `creator_fee_bps`
+/// is capped at `MAX_CREATOR_FEE_BPS` (< 10_000) at create time, so the fee is
+/// always `<= amount`.
+#[inline(never)]
+fn compute_creator_fee(amount: u64, creator_fee_bps: u16) -> Result<u64> {
+    let fee = (amount as u128)
+        .checked_mul(creator_fee_bps as u128)
+        .ok_or(MarketsError::MathOverflow)?
+        / 10_000u128;
+    let fee = u64::try_from(fee).map_err(|_| MarketsError::MathOverflow)?;
+    Ok(fee)
+}
+
 /// Accounts for `initialize_markets_config` (Phase 0).
 ///
 /// Mirrors wzrd-rails' `InitializeConfig`: a single `init` of the config PDA by
@@ -2147,7 +2461,14 @@ pub struct InitializeMarketTokens<'info> {
 
     /// USDC collateral mint (the vault holds this). Pinned to the config mint so
     /// the collateral cannot be swapped at token-init time.
+    ///
+    /// The raw owner `constraint` deliberately duplicates `mint::token_program`:
+    /// Anchor evaluates it first, so a swapped USDC token program fails with the
+    /// typed `TokenProgramMismatch` clients match on, rather than Anchor's
+    /// generic `ConstraintMintTokenProgram`. Keep the two in sync.
     #[account(
+        constraint = *usdc_mint.to_account_info().owner == usdc_token_program.key()
+            @ MarketsError::TokenProgramMismatch,
         address = config.usdc_mint @ MarketsError::InvalidMarketState,
         mint::token_program = usdc_token_program,
     )]
@@ -2792,6 +3113,23 @@ pub struct Swap<'info> {
     pub outcome_token_program: Program<'info, Token2022>,
 }
 
+/// Accounts for `market_odds` (Phase 2). Read-only: the market and its pool.
+#[derive(Accounts)]
+pub struct MarketOdds<'info> {
+    #[account(
+        seeds = [MARKET_SEED, &market.market_id.to_le_bytes()],
+        bump = market.bump,
+    )]
+    pub market: Account<'info, Market>,
+
+    #[account(
+        seeds = [POOL_SEED, market.key().as_ref()],
+        bump = pool.bump,
+        has_one = market @ MarketsError::InvalidMarketState,
+    )]
+    pub pool: Account<'info, Pool>,
+}
+
 // ─── Phase 3 Accounts ─────────────────────────────────────────────────────────
 
 /// Accounts for `initialize_attention_root_config` (Phase 3).
@@ -3021,6 +3359,122 @@ pub struct Settle<'info> {
     pub usdc_token_program: Interface<'info, TokenInterface>,
 }
 
+/// Accounts for `creator_revenue` (Phase 3). Read-only: the market alone.
+#[derive(Accounts)]
+pub struct CreatorRevenue<'info> {
+    #[account(
+        seeds = [MARKET_SEED, &market.market_id.to_le_bytes()],
+        bump = market.bump,
+    )]
+    pub market: Account<'info, Market>,
+}
+
+/// Accounts for `claim_creator_fees` (Phase 3).
+///
+/// The market creator signs (checked in the handler) and names any USDC account
+/// as the destination; the Market PDA signs the transfer out of the vault.
+#[derive(Accounts)]
+pub struct ClaimCreatorFees<'info> {
+    pub creator: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [MARKET_SEED, &market.market_id.to_le_bytes()],
+        bump = market.bump,
+    )]
+    pub market: Account<'info, Market>,
+
+    #[account(
+        seeds = [MARKETS_CONFIG_SEED],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, MarketsConfig>,
+
+    /// USDC collateral mint (pinned to the config mint).
+    #[account(
+        address = config.usdc_mint @ MarketsError::InvalidMarketState,
+        mint::token_program = usdc_token_program,
+    )]
+    pub usdc_mint: Box<InterfaceAccount<'info, MintInterface>>,
+
+    /// USDC vault (PDA-owned by the market; the market signs the transfer-out).
+    #[account(
+        mut,
+        address = market.vault @ MarketsError::AccountMismatch,
+        token::mint = usdc_mint,
+        token::authority = market,
+        token::token_program = usdc_token_program,
+    )]
+    pub vault: Box<InterfaceAccount<'info, TokenAccountInterface>>,
+
+    /// Fee destination (creator-chosen USDC account).
+    #[account(
+        mut,
+        token::mint = usdc_mint,
+        token::token_program = usdc_token_program,
+    )]
+    pub creator_usdc: Box<InterfaceAccount<'info, TokenAccountInterface>>,
+
+    /// Token program backing USDC.
+    pub usdc_token_program: Interface<'info, TokenInterface>,
+}
+
+/// Accounts for `push_creator_fees` (Phase 3).
+///
+/// The admin signs (checked in the handler); the destination is pinned to the
+/// creator's canonical USDC ATA so the admin cannot redirect the fees. The
+/// Market PDA signs the transfer out of the vault.
+#[derive(Accounts)]
+pub struct PushCreatorFees<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [MARKETS_CONFIG_SEED],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, MarketsConfig>,
+
+    #[account(
+        mut,
+        seeds = [MARKET_SEED, &market.market_id.to_le_bytes()],
+        bump = market.bump,
+    )]
+    pub market: Account<'info, Market>,
+
+    /// USDC collateral mint (pinned to the config mint).
+    #[account(
+        address = config.usdc_mint @ MarketsError::InvalidMarketState,
+        mint::token_program = usdc_token_program,
+    )]
+    pub usdc_mint: Box<InterfaceAccount<'info, MintInterface>>,
+
+    /// USDC vault (PDA-owned by the market; the market signs the transfer-out).
+    #[account(
+        mut,
+        address = market.vault @ MarketsError::AccountMismatch,
+        token::mint = usdc_mint,
+        token::authority = market,
+        token::token_program = usdc_token_program,
+    )]
+    pub vault: Box<InterfaceAccount<'info, TokenAccountInterface>>,
+
+    /// CHECK: the market creator's wallet; only used to derive its ATA.
+    #[account(address = market.creator @ MarketsError::AccountMismatch)]
+    pub creator: UncheckedAccount<'info>,
+
+    /// Fee destination: the creator's canonical USDC ATA.
+    #[account(
+        mut,
+        associated_token::mint = usdc_mint,
+        associated_token::authority = creator,
+        associated_token::token_program = usdc_token_program,
+    )]
+    pub creator_usdc: Box<InterfaceAccount<'info, TokenAccountInterface>>,
+
+    /// Token program backing USDC.
+    pub usdc_token_program: Interface<'info, TokenInterface>,
+}
+
 /// Accounts for `resolve_override` (Phase 3).
 ///
 /// The `resolver_multisig` signer (a Squads V4 vault PDA enforcing its own M-of-N
@@ -3103,6 +3557,80 @@ pub struct SweepResidual<'info> {
     pub usdc_token_program: Interface<'info, TokenInterface>,
 }
 
+/// Accounts for `cancel_market`.
+///
+/// Admin cancels a never-used market. `close = rent_recipient` on the Market is
+/// the rent-return; the handler's empty-market guards run first. The mints,
+/// vault and recipient are omitted for a market whose tokens were never
+/// initialized.
+#[derive(Accounts)]
+pub struct CancelMarket<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [MARKETS_CONFIG_SEED],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, MarketsConfig>,
+
+    #[account(
+        mut,
+        close = rent_recipient,
+        seeds = [MARKET_SEED, &market.market_id.to_le_bytes()],
+        bump = market.bump,
+    )]
+    pub market: Account<'info, Market>,
+
+    /// CHECK: Pool PDA for this market; must be uninitialized (checked in the
+    /// handler). Seeds pin the address so an existing pool cannot be hidden.
+    #[account(
+        seeds = [POOL_SEED, market.key().as_ref()],
+        bump,
+    )]
+    pub pool: UncheckedAccount<'info>,
+
+    /// YES outcome mint (read for the supply guard).
+    #[account(address = market.yes_mint @ MarketsError::AccountMismatch)]
+    pub yes_mint: Option<Box<InterfaceAccount<'info, MintInterface>>>,
+
+    /// NO outcome mint (read for the supply guard).
+    #[account(address = market.no_mint @ MarketsError::AccountMismatch)]
+    pub no_mint: Option<Box<InterfaceAccount<'info, MintInterface>>>,
+
+    /// USDC collateral mint (pinned to the config mint).
+    #[account(
+        address = config.usdc_mint @ MarketsError::InvalidMarketState,
+        mint::token_program = usdc_token_program,
+    )]
+    pub usdc_mint: Box<InterfaceAccount<'info, MintInterface>>,
+
+    /// USDC vault — emptied to `recipient` then closed via CPI in the handler body.
+    #[account(
+        mut,
+        address = market.vault @ MarketsError::AccountMismatch,
+        token::mint = usdc_mint,
+        token::authority = market,
+        token::token_program = usdc_token_program,
+    )]
+    pub vault: Option<Box<InterfaceAccount<'info, TokenAccountInterface>>>,
+
+    /// Destination for any USDC left in the vault (admin-chosen treasury account).
+    /// Required only when the vault is non-empty.
+    #[account(
+        mut,
+        token::mint = usdc_mint,
+        token::token_program = usdc_token_program,
+    )]
+    pub recipient: Option<Box<InterfaceAccount<'info, TokenAccountInterface>>>,
+
+    /// CHECK: rent destination for the closed Market and vault accounts.
+    #[account(mut)]
+    pub rent_recipient: UncheckedAccount<'info>,
+
+    /// Token program backing USDC (required for the transfer and vault close CPIs).
+    pub usdc_token_program: Interface<'info, TokenInterface>,
+}
+
 /// Accounts for `close_market` (Phase 3).
 ///
 /// Admin closes a fully-settled, drained market and reclaims its rent to
@@ -198,6 +198,7 @@ pub fn compute_root_from_proof(leaf_hash: [u8; 32], proof: &[[u8; 32]]) -> [u8;
 #[cfg(test)]
 mod tests {
     use super::*;
+    use proptest::prelude::*;
 
     /// Non-zero fixture leaf, by NAMED fields to avoid positional-arg confusion
     /// between the `metric` and `outcome` slots. market_id=0x0102030405060708,
@@ -299,6 +300,35 @@ mod tests {
         );
     }
 
+    /// The hash primitive itself is Keccak-256 (the pre-NIST padding Ethereum
+    /// uses), not SHA-256 and not NIST SHA3-256. Anchor discriminators use
+    /// SHA-256, so a leaf hashed with the wrong function is an easy slip in a
+    /// prover; pin the empty-input digests so a swapped hasher fails here.
+    #[test]
+    fn hash_primitive_is_keccak256() {
+        let keccak_empty = [
+            0xc5, 0xd2, 0x46, 0x01, 0x86, 0xf7, 0x23, 0x3c, 0x92, 0x7e, 0x7d, 0xb2, 0xdc, 0xc7,
+            0x03, 0xc0, 0xe5, 0x00, 0xb6, 0x53, 0xca, 0x82, 0x27, 0x3b, 0x7b, 0xfa, 0xd8, 0x04,
+            0x5d, 0x85, 0xa4, 0x70,
+        ];
+        let sha256_empty = [
+            0xe3, 0xb0, 0xc4, 0x42, 0x98, 0xfc, 0x1c, 0x14, 0x9a, 0xfb, 0xf4, 0xc8, 0x99, 0x6f,
+            0xb9, 0x24, 0x27, 0xae, 0x41, 0xe4, 0x64, 0x9b, 0x93, 0x4c, 0xa4, 0x95, 0x99, 0x1b,
+            0x78, 0x52, 0xb8, 0x55,
+        ];
+        let digest = keccak::hashv(&[]).to_bytes();
+        assert_eq!(digest, keccak_empty);
+        assert_ne!(digest, sha256_empty);
+
+        // The leaf hash is exactly keccak(LEAF_DOMAIN || canonical_bytes).
+        let leaf = fixture_leaf();
+        let bytes = leaf.canonical_bytes();
+        assert_eq!(
+            leaf.hash(),
+            keccak::hashv(&[MARKETS_RESOLUTION_LEAF_V1_DOMAIN, bytes.as_ref()]).to_bytes()
+        );
+    }
+
     /// Determinism baseline. All-zero leaf EXCEPT `schema_version = 1` (the only
     /// valid value). The off-chain Rust/TS tree-builder mirror MUST match this
     /// byte-for-byte; a mismatch = silent unverifiability (the M-04 failure made
@@ -446,4 +476,85 @@ mod tests {
         tampered[0] ^= 1;
         assert_ne!(compute_root_from_proof(ha, &[tampered]), root);
     }
+
+    /// Test-only tree builder under the v1 convention. A lone trailing node at
+    /// any level is carried up unchanged, so its proof simply skips that level.
+    /// Returns `(root, proofs)` with one proof per leaf.
+    fn build_tree(leaf_hashes: &[[u8; 32]]) -> ([u8; 32], Vec<Vec<[u8; 32]>>) {
+        let mut proofs = vec![Vec::new(); leaf_hashes.len()];
+        // Position of each original leaf within the current level.
+        let mut positions: Vec<usize> = (0..leaf_hashes.len()).collect();
+        let mut level = leaf_hashes.to_vec();
+        while level.len() > 1 {
+            for (leaf, pos) in positions.iter_mut().enumerate() {
+                let sibling = *pos ^ 1;
+                if sibling < level.len() {
+                    proofs[leaf].push(level[sibling]);
+                }
+                *pos /= 2;
+            }
+            level = level
+                .chunks(2)
+                .map(|pair| match pair {
+                    [l, r] => markets_resolution_node_hash_v1(l, r),
+                    [lone] => *lone,
+                    _ => unreachable!(),
+                })
+                .collect();
+        }
+        (level[0], proofs)
+    }
+
+    proptest! {
+        /// Round-trip over random leaf sets (single leaf, power-of-two and not,
+        /// optionally with a duplicated leaf): every proof folds back to the root,
+        /// and flipping one byte of the leaf, a sibling, or the root breaks it.
+        #[test]
+        fn random_tree_proofs_verify_and_mutations_fail(
+            values in prop::collection::vec(any::<u64>(), 1..=33),
+            duplicate_first in any::<bool>(),
+            pick in any::<usize>(),
+            byte in 0usize..32,
+        ) {
+            let mut leaves: Vec<MarketsResolutionLeafV1> = values
+                .iter()
+                .enumerate()
+                .map(|(i, v)| {
+                    MarketsResolutionLeafV1::new(7, [i as u8; 32], 1, 0, *v, super::outcome::YES)
+                })
+                .collect();
+            if duplicate_first {
+                leaves.push(leaves[0]);
+            }
+            let hashes: Vec<[u8; 32]> = leaves.iter().map(MarketsResolutionLeafV1::hash).collect();
+            let (root, proofs) = build_tree(&hashes);
+
+            for (hash, proof) in hashes.iter().zip(proofs.iter()) {
+                prop_assert!(proof.len() <= MARKETS_MAX_PROOF_LEN);
+                prop_assert_eq!(compute_root_from_proof(*hash, proof), root);
+            }
+
+            let i = pick % leaves.len();
+            let mut tampered_leaf = leaves[i];
+            tampered_leaf.observed_value ^= 1;
+            prop_assert_ne!(
+                compute_root_from_proof(tampered_leaf.hash(), &proofs[i]),
+                root
+            );
+
+            let mut tampered_root = root;
+            tampered_root[byte] ^= 0x01;
+            prop_assert_ne!(compute_root_from_proof(hashes[i], &proofs[i]), tampered_root);
+
+            if !proofs[i].is_empty() {
+                let mut tampered_proof = proofs[i].clone();
+                let level = pick % tampered_proof.len();
+                tampered_proof[level][byte] ^= 0x01;
+                prop_assert_ne!(
+                    compute_root_from_proof(hashes[i], &tampered_proof),
+                    root
+                );
+            }
+        }
+    }
 }
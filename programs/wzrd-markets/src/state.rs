@@ -210,10 +210,25 @@ pub struct Market {
     /// the admin cannot indefinitely postpone settlement. CARVED from
     /// `_reserved` (no realloc).
     pub dispute_extended: bool,
+    /// Share of every `settle` payout (basis points) withheld for the creator
+    /// for hosting the market. Fixed at `create_market`, capped at
+    /// `MAX_CREATOR_FEE_BPS`. Zero (the value every pre-existing market reads
+    /// from its zeroed reserve) means no fee. CARVED from `_reserved` (47 → 45).
+    pub creator_fee_bps: u16,
+    /// Creator fees `settle` has withheld in the vault and `claim_creator_fees`
+    /// has not yet paid out. Drops to 0 on every claim. CARVED from
+    /// `_reserved` (45 → 37).
+    pub creator_fees_accrued: u64,
+    /// Lifetime creator fees `settle` has credited to the creator, claimed or
+    /// not. Only ever grows; read through the `creator_revenue` view. CARVED
+    /// from `_reserved` (37 → 29).
+    pub creator_fees_paid: u64,
     /// Forward-compat reserve. Phase 0 reserved 64 bytes; Phase 3 carved
     /// 8 (resolved_at_slot) + 8 (settle_unlock_slot) + 1 (dispute_extended) = 17,
-    /// leaving 47 — total LEN unchanged, no realloc on existing markets.
-    pub _reserved: [u8; 47],
+    /// then 2 for `creator_fee_bps`, 8 for `creator_fees_accrued` and 8 for
+    /// `creator_fees_paid`, leaving 29 — total LEN unchanged, no realloc on
+    /// existing markets.
+    pub _reserved: [u8; 29],
 }
 
 impl Market {
@@ -225,10 +240,11 @@ impl Market {
     ///   + 8 created_slot + 8 resolve_deadline_slot + 1 resolved + 1 outcome
     ///   + 8 settled_supply + 8 dispute_window_slots + 8 resolved_at_slot
     ///   + 8 settle_unlock_slot + 32 yes_mint + 32 no_mint + 32 vault
-    ///   + 1 tokens_initialized + 1 dispute_extended + 47 reserved.
-    /// Phase 3 carved 17 bytes (resolved_at_slot + settle_unlock_slot +
-    /// dispute_extended) from the Phase-0 64-byte reserve (now 47); `outcome`
-    /// went bool->u8 (width-neutral). Total LEN is UNCHANGED at 326 — no realloc.
+    ///   + 1 tokens_initialized + 1 dispute_extended + 2 creator_fee_bps
+    ///   + 8 creator_fees_accrued + 8 creator_fees_paid + 29 reserved.
+    /// Phase 3 carved 17 bytes and the creator fee 18 more from the Phase-0
+    /// 64-byte reserve (now 29); `outcome` went bool->u8 (width-neutral).
+    /// Total LEN is UNCHANGED at 326 — no realloc.
     pub const LEN: usize = 8
         + 1
         + 1
@@ -252,7 +268,10 @@ impl Market {
         + 32
         + 1
         + 1
-        + 47;
+        + 2
+        + 8
+        + 8
+        + 29;
 }
 
 /// The constant-product (`x * y = k`) pool over a market's YES/NO outcome
@@ -363,14 +382,17 @@ pub struct AttentionRoot {
     pub published_at_slot: u64,
     /// The allow-listed publisher that wrote this root.
     pub publisher: Pubkey,
-    /// Forward-compat reserve.
-    pub _reserved: [u8; 32],
+    /// Publisher-committed hash of the input dataset the tree was built from
+    /// (mirrors AO's `RootEntry.dataset_hash`). Lets a disputed root be tied back
+    /// to the exact off-chain sample set that produced it. Opaque to the program.
+    /// CARVED from the full 32-byte `_reserved` — LEN unchanged, no realloc.
+    pub dataset_hash: [u8; 32],
 }
 
 impl AttentionRoot {
     /// Account size including the 8-byte Anchor discriminator.
     /// 8 disc + 1 bump + 8 window_id + 32 merkle_root + 4 leaf_count
-    ///   + 1 schema_version + 8 published_at_slot + 32 publisher + 32 reserved.
+    ///   + 1 schema_version + 8 published_at_slot + 32 publisher + 32 dataset_hash.
     pub const LEN: usize = 8 + 1 + 8 + 32 + 4 + 1 + 8 + 32 + 32;
 }
 
@@ -435,6 +457,8 @@ mod tests {
     #[test]
     fn attention_root_len_matches_manual_calc() {
         // 8 + 1 + 8 + 32 + 4 + 1 + 8 + 32 + 32 = 126.
+        // dataset_hash (32) was carved from the original 32-byte reserve, so the
+        // LEN is unchanged (publish contract revision 2, conventions §2.1).
         // Cross-repo contract surface — Team A's builder publishes into this
         // layout. A change here is a v1 contract version bump.
         assert_eq!(AttentionRoot::LEN, 126);
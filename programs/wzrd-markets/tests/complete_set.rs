@@ -46,7 +46,7 @@ use wzrd_markets::{
     accounts as markets_accounts, instruction as markets_ix,
     state::{
         Market, MarketMetric, MarketsConfig, MARKETS_CONFIG_SEED, MARKET_SEED, MINT_AUTH_SEED,
-        NO_MINT_SEED, VAULT_SEED, YES_MINT_SEED,
+        NO_MINT_SEED, POOL_SEED, VAULT_SEED, YES_MINT_SEED,
     },
     MarketsError, ID as WZRD_MARKETS_PROGRAM_ID,
 };
@@ -237,6 +237,13 @@ fn mint_auth_pda(market_id: u64) -> (LegacyPubkey, u8) {
     (LegacyPubkey::new_from_array(addr.to_bytes()), bump)
 }
 
+/// Pool PDA: `[POOL_SEED, market.key()]`.
+fn pool_pda(market: &LegacyPubkey) -> (LegacyPubkey, u8) {
+    let (addr, bump) =
+        Pubkey::find_program_address(&[POOL_SEED, &market.to_bytes()], &WZRD_MARKETS_PROGRAM_ID);
+    (LegacyPubkey::new_from_array(addr.to_bytes()), bump)
+}
+
 fn derive_ata(owner: &LegacyPubkey, mint: &LegacyPubkey) -> LegacyPubkey {
     get_associated_token_address_with_program_id(owner, mint, &spl_token_2022::id())
 }
@@ -402,6 +409,7 @@ fn build_create_market_ix(
             resolution_root_seq,
             resolve_deadline_slot,
             dispute_window_slots,
+            creator_fee_bps: 0,
         }
         .data(),
     }
@@ -439,6 +447,41 @@ fn build_initialize_market_tokens_ix(
     }
 }
 
+/// `yes_mint`/`no_mint`/`vault` are `None` for a market whose tokens were never
+/// initialized, and `recipient` is `None` when the vault is empty (Anchor
+/// encodes an omitted optional account as the program id).
+#[allow(clippy::too_many_arguments)]
+fn build_cancel_market_ix(
+    admin: LegacyPubkey,
+    config: LegacyPubkey,
+    market: LegacyPubkey,
+    usdc_mint: LegacyPubkey,
+    yes_mint: Option<LegacyPubkey>,
+    no_mint: Option<LegacyPubkey>,
+    vault: Option<LegacyPubkey>,
+    recipient: Option<LegacyPubkey>,
+    rent_recipient: LegacyPubkey,
+) -> LegacyInstruction {
+    LegacyInstruction {
+        program_id: WZRD_MARKETS_PROGRAM_ID,
+        accounts: markets_accounts::CancelMarket {
+            admin,
+            config,
+            market,
+            pool: pool_pda(&market).0,
+            yes_mint,
+            no_mint,
+            usdc_mint,
+            vault,
+            recipient,
+            rent_recipient,
+            usdc_token_program: spl_token_2022::id(),
+        }
+        .to_account_metas(None),
+        data: markets_ix::CancelMarket {}.data(),
+    }
+}
+
 #[allow(clippy::too_many_arguments)]
 fn build_mint_complete_set_ix(
     depositor: LegacyPubkey,
@@ -791,6 +834,83 @@ fn complete_set_roundtrip_preserves_solvency() {
     );
 }
 
+/// Complete sets stay exact all the way up to `u64::MAX`. Every handler step is
+/// checked (`net_received` via `checked_sub`); the supplies can never pass the
+/// vault, and the vault can never pass the USDC mint supply, itself capped at
+/// `u64::MAX`. Mint the whole USDC supply in two sets and redeem it back.
+#[test]
+fn complete_set_near_u64_max_stays_exact() {
+    let mut f = setup();
+    // Top the depositor up so the USDC mint supply is exactly u64::MAX.
+    mint_token_2022(
+        &mut f.svm,
+        &f.usdc_mint_authority,
+        &f.usdc_mint,
+        &f.depositor_usdc,
+        u64::MAX - DEPOSITOR_USDC_FUNDING,
+    );
+    assert_eq!(read_mint_supply(&f.svm, &f.usdc_mint), u64::MAX);
+
+    let mint_set = |amount: u64| {
+        build_mint_complete_set_ix(
+            legacy_from_signer(&f.depositor),
+            f.market,
+            f.config,
+            f.usdc_mint,
+            f.yes_mint,
+            f.no_mint,
+            f.vault,
+            f.depositor_usdc,
+            f.depositor_yes,
+            f.depositor_no,
+            f.mint_authority,
+            amount,
+        )
+    };
+    let first = mint_set(u64::MAX - 1);
+    let too_much = mint_set(2);
+    let last = mint_set(1);
+
+    send_tx(&mut f.svm, &[&f.depositor], &[first]);
+    assert_eq!(read_token_balance(&f.svm, &f.vault), u64::MAX - 1);
+    assert_eq!(read_mint_supply(&f.svm, &f.yes_mint), u64::MAX - 1);
+    assert_eq!(read_mint_supply(&f.svm, &f.no_mint), u64::MAX - 1);
+
+    // Only 1 USDC is left to deposit: asking for 2 fails and changes nothing.
+    f.svm.expire_blockhash();
+    let result = try_send_tx(&mut f.svm, &[&f.depositor], &[too_much]);
+    assert!(result.is_err(), "deposit beyond the USDC supply must fail");
+    assert_eq!(read_token_balance(&f.svm, &f.vault), u64::MAX - 1);
+    assert_eq!(read_mint_supply(&f.svm, &f.yes_mint), u64::MAX - 1);
+
+    f.svm.expire_blockhash();
+    send_tx(&mut f.svm, &[&f.depositor], &[last]);
+    assert_eq!(read_token_balance(&f.svm, &f.vault), u64::MAX);
+    assert_eq!(read_mint_supply(&f.svm, &f.yes_mint), u64::MAX);
+    assert_eq!(read_mint_supply(&f.svm, &f.no_mint), u64::MAX);
+    assert_eq!(read_token_balance(&f.svm, &f.depositor_usdc), 0);
+
+    f.svm.expire_blockhash();
+    let ix = build_redeem_complete_set_ix(
+        legacy_from_signer(&f.depositor),
+        f.market,
+        f.config,
+        f.usdc_mint,
+        f.yes_mint,
+        f.no_mint,
+        f.vault,
+        f.depositor_usdc,
+        f.depositor_yes,
+        f.depositor_no,
+        u64::MAX,
+    );
+    send_tx(&mut f.svm, &[&f.depositor], &[ix]);
+    assert_eq!(read_token_balance(&f.svm, &f.vault), 0);
+    assert_eq!(read_mint_supply(&f.svm, &f.yes_mint), 0);
+    assert_eq!(read_mint_supply(&f.svm, &f.no_mint), 0);
+    assert_eq!(read_token_balance(&f.svm, &f.depositor_usdc), u64::MAX);
+}
+
 #[test]
 fn mint_complete_set_rejects_zero() {
     let mut f = setup();
@@ -949,6 +1069,290 @@ fn double_initialize_market_tokens_rejected() {
     );
 }
 
+#[test]
+fn initialize_market_tokens_rejects_swapped_usdc_token_program() {
+    let mut f = setup();
+    let next_id: u64 = 1;
+    let (market1, _) = market_pda(next_id);
+    let deadline = future_deadline_slot(&f.svm);
+    let ix = build_create_market_ix(
+        legacy_from_signer(&f.admin),
+        f.config,
+        market1,
+        next_id,
+        [1u8; 32],
+        MarketMetric::PEAK_VIEWERS,
+        500,
+        [3u8; 32],
+        1,
+        deadline,
+        50,
+    );
+    send_tx(&mut f.svm, &[&f.admin], &[ix]);
+
+    // The fixture USDC is a Token-2022 mint; hand the IX the legacy SPL Token
+    // program in the `usdc_token_program` slot instead.
+    let mut ix = build_initialize_market_tokens_ix(
+        legacy_from_signer(&f.admin),
+        f.config,
+        market1,
+        f.usdc_mint,
+        yes_mint_pda(next_id).0,
+        no_mint_pda(next_id).0,
+        vault_pda(next_id).0,
+        mint_auth_pda(next_id).0,
+    );
+    // `usdc_token_program` follows `outcome_token_program` (both Token-2022 in
+    // the fixture), so it is the last meta carrying the Token-2022 program id.
+    let usdc_token_program = ix
+        .accounts
+        .iter()
+        .rposition(|meta| meta.pubkey == spl_token_2022::id())
+        .expect("usdc_token_program account meta");
+    ix.accounts[usdc_token_program].pubkey = anchor_spl::token::ID;
+    let result = try_send_tx(&mut f.svm, &[&f.admin], &[ix]);
+    assert_markets_error(result, MarketsError::TokenProgramMismatch);
+}
+
+#[test]
+fn cancel_market_reclaims_rent_of_unused_market() {
+    const DONATION: u64 = 5_000_000;
+    let mut f = setup();
+    let admin = legacy_from_signer(&f.admin);
+    let outsider = Keypair::new();
+    f.svm
+        .airdrop(&outsider.pubkey(), 1_000_000_000)
+        .expect("airdrop outsider");
+    let cancel = build_cancel_market_ix(
+        legacy_from_signer(&outsider),
+        f.config,
+        f.market,
+        f.usdc_mint,
+        Some(f.yes_mint),
+        Some(f.no_mint),
+        Some(f.vault),
+        None,
+        admin,
+    );
+    let result = try_send_tx(&mut f.svm, &[&outsider], &[cancel]);
+    assert_markets_error(result, MarketsError::Unauthorized);
+
+    // Donated USDC has no claimant: it moves to the admin's recipient account
+    // (never burned), whatever its size.
+    mint_token_2022(
+        &mut f.svm,
+        &f.usdc_mint_authority,
+        &f.usdc_mint,
+        &f.vault,
+        DONATION,
+    );
+    let usdc_supply_before = read_mint_supply(&f.svm, &f.usdc_mint);
+    let admin_usdc = create_ata(&mut f.svm, &f.admin, &admin, &f.usdc_mint);
+
+    let admin_lamports_before = f
+        .svm
+        .get_account(&address_from_legacy(&admin))
+        .expect("admin exists")
+        .lamports;
+    let cancel = build_cancel_market_ix(
+        admin,
+        f.config,
+        f.market,
+        f.usdc_mint,
+        Some(f.yes_mint),
+        Some(f.no_mint),
+        Some(f.vault),
+        Some(admin_usdc),
+        admin,
+    );
+    send_tx(&mut f.svm, &[&f.admin], &[cancel]);
+
+    for closed in [f.market, f.vault] {
+        let gone = f
+            .svm
+            .get_account(&address_from_legacy(&closed))
+            .is_none_or(|account| account.lamports == 0);
+        assert!(gone, "{closed} should be closed");
+    }
+    assert_eq!(read_token_balance(&f.svm, &admin_usdc), DONATION);
+    assert_eq!(
+        read_mint_supply(&f.svm, &f.usdc_mint),
+        usdc_supply_before,
+        "no USDC burned"
+    );
+    let admin_lamports_after = f
+        .svm
+        .get_account(&address_from_legacy(&admin))
+        .expect("admin exists")
+        .lamports;
+    assert!(admin_lamports_after > admin_lamports_before);
+}
+
+#[test]
+fn cancel_market_rejects_stale_creator_and_missing_recipient() {
+    let mut f = setup();
+    mint_token_2022(
+        &mut f.svm,
+        &f.usdc_mint_authority,
+        &f.usdc_mint,
+        &f.vault,
+        1,
+    );
+
+    // Rotate the admin away from the market's recorded creator; the stale
+    // creator key is no longer an authority — only config.admin is.
+    let new_admin = Keypair::new();
+    f.svm
+        .airdrop(&new_admin.pubkey(), 1_000_000_000)
+        .expect("airdrop new admin");
+    let propose = LegacyInstruction {
+        program_id: WZRD_MARKETS_PROGRAM_ID,
+        accounts: markets_accounts::AdminConfig {
+            admin: legacy_from_signer(&f.admin),
+            config: f.config,
+        }
+        .to_account_metas(None),
+        data: markets_ix::SetAdmin {
+            new_admin: anchor_pubkey(legacy_from_signer(&new_admin)),
+        }
+        .data(),
+    };
+    send_tx(&mut f.svm, &[&f.admin], &[propose]);
+    let accept = LegacyInstruction {
+        program_id: WZRD_MARKETS_PROGRAM_ID,
+        accounts: markets_accounts::AcceptAdmin {
+            new_admin: legacy_from_signer(&new_admin),
+            config: f.config,
+        }
+        .to_account_metas(None),
+        data: markets_ix::AcceptAdmin {}.data(),
+    };
+    send_tx(&mut f.svm, &[&new_admin], &[accept]);
+    let market: Market = read_anchor_account(&f.svm, &f.market);
+    assert_eq!(market.creator, anchor_pubkey(legacy_from_signer(&f.admin)));
+
+    let cancel = build_cancel_market_ix(
+        legacy_from_signer(&f.admin),
+        f.config,
+        f.market,
+        f.usdc_mint,
+        Some(f.yes_mint),
+        Some(f.no_mint),
+        Some(f.vault),
+        None,
+        legacy_from_signer(&f.admin),
+    );
+    let result = try_send_tx(&mut f.svm, &[&f.admin], &[cancel]);
+    assert_markets_error(result, MarketsError::Unauthorized);
+
+    let cancel = build_cancel_market_ix(
+        legacy_from_signer(&new_admin),
+        f.config,
+        f.market,
+        f.usdc_mint,
+        Some(f.yes_mint),
+        Some(f.no_mint),
+        Some(f.vault),
+        None,
+        legacy_from_signer(&new_admin),
+    );
+    // A non-empty vault needs somewhere to send its USDC.
+    let result = try_send_tx(&mut f.svm, &[&new_admin], &[cancel]);
+    assert_markets_error(result, MarketsError::AccountMismatch);
+}
+
+#[test]
+fn cancel_market_without_initialized_tokens() {
+    let mut f = setup();
+    let admin = legacy_from_signer(&f.admin);
+    let market_id = MARKET_ID + 1;
+    let (market, _) = market_pda(market_id);
+    let deadline = future_deadline_slot(&f.svm);
+    let ix = build_create_market_ix(
+        admin,
+        f.config,
+        market,
+        market_id,
+        [7u8; 32],
+        MarketMetric::AVG_VIEWERS,
+        1_000,
+        [9u8; 32],
+        42,
+        deadline,
+        150,
+    );
+    send_tx(&mut f.svm, &[&f.admin], &[ix]);
+
+    // Tokens never initialized: the mints, vault and recipient are omitted.
+    let cancel = build_cancel_market_ix(
+        admin,
+        f.config,
+        market,
+        f.usdc_mint,
+        None,
+        None,
+        None,
+        None,
+        admin,
+    );
+    send_tx(&mut f.svm, &[&f.admin], &[cancel]);
+    let gone = f
+        .svm
+        .get_account(&address_from_legacy(&market))
+        .is_none_or(|account| account.lamports == 0);
+    assert!(gone, "{market} should be closed");
+
+    // An initialized market must pass its mints and vault.
+    let cancel = build_cancel_market_ix(
+        admin,
+        f.config,
+        f.market,
+        f.usdc_mint,
+        None,
+        None,
+        None,
+        None,
+        admin,
+    );
+    let result = try_send_tx(&mut f.svm, &[&f.admin], &[cancel]);
+    assert_markets_error(result, MarketsError::AccountMismatch);
+}
+
+#[test]
+fn cancel_market_rejects_market_with_shares() {
+    let mut f = setup();
+    let ix = build_mint_complete_set_ix(
+        legacy_from_signer(&f.depositor),
+        f.market,
+        f.config,
+        f.usdc_mint,
+        f.yes_mint,
+        f.no_mint,
+        f.vault,
+        f.depositor_usdc,
+        f.depositor_yes,
+        f.depositor_no,
+        f.mint_authority,
+        SET_AMOUNT,
+    );
+    send_tx(&mut f.svm, &[&f.depositor], &[ix]);
+
+    let cancel = build_cancel_market_ix(
+        legacy_from_signer(&f.admin),
+        f.config,
+        f.market,
+        f.usdc_mint,
+        Some(f.yes_mint),
+        Some(f.no_mint),
+        Some(f.vault),
+        None,
+        legacy_from_signer(&f.admin),
+    );
+    let result = try_send_tx(&mut f.svm, &[&f.admin], &[cancel]);
+    assert_markets_error(result, MarketsError::SupplyNotZero);
+    assert_eq!(read_token_balance(&f.svm, &f.vault), SET_AMOUNT);
+}
+
 #[test]
 fn non_sequential_market_id_rejected() {
     let mut f = setup();
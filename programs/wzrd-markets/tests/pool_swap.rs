@@ -408,6 +408,7 @@ fn build_create_market_ix(
             resolution_root_seq,
             resolve_deadline_slot,
             dispute_window_slots,
+            creator_fee_bps: 0,
         }
         .data(),
     }
@@ -679,6 +680,45 @@ fn build_swap_ix(
     }
 }
 
+fn build_market_odds_ix(market: LegacyPubkey, pool: LegacyPubkey) -> LegacyInstruction {
+    LegacyInstruction {
+        program_id: WZRD_MARKETS_PROGRAM_ID,
+        accounts: markets_accounts::MarketOdds { market, pool }.to_account_metas(None),
+        data: markets_ix::MarketOdds {}.data(),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn build_cancel_market_ix(
+    admin: LegacyPubkey,
+    config: LegacyPubkey,
+    market: LegacyPubkey,
+    pool: LegacyPubkey,
+    usdc_mint: LegacyPubkey,
+    yes_mint: LegacyPubkey,
+    no_mint: LegacyPubkey,
+    vault: LegacyPubkey,
+) -> LegacyInstruction {
+    LegacyInstruction {
+        program_id: WZRD_MARKETS_PROGRAM_ID,
+        accounts: markets_accounts::CancelMarket {
+            admin,
+            config,
+            market,
+            pool,
+            yes_mint: Some(yes_mint),
+            no_mint: Some(no_mint),
+            usdc_mint,
+            vault: Some(vault),
+            recipient: None,
+            rent_recipient: admin,
+            usdc_token_program: spl_token_2022::id(),
+        }
+        .to_account_metas(None),
+        data: markets_ix::CancelMarket {}.data(),
+    }
+}
+
 // ─── fixture ──────────────────────────────────────────────────────────────────
 
 struct Fixture {
@@ -923,6 +963,23 @@ fn pool_state(f: &Fixture) -> Pool {
     read_anchor_account::<Pool>(&f.svm, &f.pool)
 }
 
+/// Run `market_odds` and decode its `u16` return data (implied YES bps).
+fn market_odds_bps(f: &mut Fixture) -> u16 {
+    f.svm.expire_blockhash();
+    let payer = Keypair::new();
+    f.svm
+        .airdrop(&payer.pubkey(), 1_000_000_000)
+        .expect("airdrop odds payer");
+    let meta = send_tx(
+        &mut f.svm,
+        &[&payer],
+        &[build_market_odds_ix(f.market, f.pool)],
+    );
+    let data = meta.return_data.data;
+    assert_eq!(data.len(), 2, "market_odds returns a u16");
+    u16::from_le_bytes([data[0], data[1]])
+}
+
 /// k = yes_reserve * no_reserve as u128 (the constant-product invariant).
 fn pool_k(f: &Fixture) -> u128 {
     let p = pool_state(f);
@@ -1106,6 +1163,49 @@ fn swap_moves_price() {
     println!("[price] NO bps {no_price_before} -> {no_price_after}");
 }
 
+/// `market_odds` reads even odds off an empty pool and a balanced one, then
+/// follows the price when a trader buys YES (NO in, YES out).
+#[test]
+fn market_odds_tracks_pool() {
+    let mut f = setup_pool();
+    assert_eq!(market_odds_bps(&mut f), 5_000, "empty pool is even odds");
+
+    let _lp = seed_liquidity(&mut f, LP_YES, LP_NO);
+    assert_eq!(
+        market_odds_bps(&mut f),
+        5_000,
+        "50/50 reserves are 5000 bps"
+    );
+
+    let trader = new_actor(&mut f, DEPOSITOR_USDC_FUNDING);
+    mint_set(&mut f, &trader, 300_000_000);
+    let ix = build_swap_ix(
+        legacy_from_signer(&trader.kp),
+        f.market,
+        f.pool,
+        f.yes_mint,
+        f.no_mint,
+        f.pool_yes,
+        f.pool_no,
+        trader.yes,
+        trader.no,
+        100_000_000,
+        0,
+        SwapDirection::NO_TO_YES,
+    );
+    send_tx(&mut f.svm, &[&trader.kp], &[ix]);
+
+    let pool = pool_state(&f);
+    let expected =
+        (pool.no_reserve as u128) * 10_000 / ((pool.yes_reserve + pool.no_reserve) as u128);
+    let odds = market_odds_bps(&mut f);
+    assert_eq!(odds as u128, expected);
+    assert!(
+        odds > 5_000,
+        "buying YES raises its implied probability: {odds}"
+    );
+}
+
 /// The FIRST swap on a fresh pool (bounding phase, NO real liquidity except a
 /// thin seed) gets a sane price near 0.5 — no revert, no div-by-zero, no 0/inf.
 /// We seed a small real reserve so the pool can actually pay out, but keep it
@@ -1585,6 +1685,28 @@ fn double_initialize_pool_rejected() {
     );
 }
 
+/// An initialized pool (even with zero real liquidity and zero outcome supply)
+/// blocks `cancel_market`: its PDA, LP mint and reserve ATAs would be stranded.
+#[test]
+fn cancel_market_rejects_market_with_pool() {
+    let mut f = setup_pool();
+    assert_eq!(read_mint_supply(&f.svm, &f.yes_mint), 0);
+    assert_eq!(read_mint_supply(&f.svm, &f.no_mint), 0);
+
+    let ix = build_cancel_market_ix(
+        legacy_from_signer(&f.admin),
+        f.config,
+        f.market,
+        f.pool,
+        f.usdc_mint,
+        f.yes_mint,
+        f.no_mint,
+        f.vault,
+    );
+    let result = try_send_tx(&mut f.svm, &[&f.admin], &[ix]);
+    assert_markets_error(result, MarketsError::PoolAlreadyExists);
+}
+
 /// Pool state after init: bounding phase active, V seeded, reserves/supply zero,
 /// lp_mint recorded, and the pool PDA bump stored (used as the signer bump — the
 /// byte-identical seeds invariant). Also verifies the pool/lp_mint PDAs derive.
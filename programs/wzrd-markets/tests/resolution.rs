@@ -437,6 +437,7 @@ fn build_create_market_ix(
     resolution_root_seq: u64,
     resolve_deadline_slot: u64,
     dispute_window_slots: u64,
+    creator_fee_bps: u16,
 ) -> LegacyInstruction {
     LegacyInstruction {
         program_id: WZRD_MARKETS_PROGRAM_ID,
@@ -456,6 +457,7 @@ fn build_create_market_ix(
             resolution_root_seq,
             resolve_deadline_slot,
             dispute_window_slots,
+            creator_fee_bps,
         }
         .data(),
     }
@@ -621,6 +623,7 @@ fn build_publish_attention_root_ix(
     attention_root: LegacyPubkey,
     window_id: u64,
     merkle_root: [u8; 32],
+    dataset_hash: [u8; 32],
     leaf_count: u32,
     schema_version: u8,
 ) -> LegacyInstruction {
@@ -637,6 +640,7 @@ fn build_publish_attention_root_ix(
         data: markets_ix::PublishAttentionRoot {
             window_id,
             merkle_root,
+            dataset_hash,
             leaf_count,
             schema_version,
         }
@@ -723,6 +727,64 @@ fn build_settle_ix(
     }
 }
 
+fn build_creator_revenue_ix(market: LegacyPubkey) -> LegacyInstruction {
+    LegacyInstruction {
+        program_id: WZRD_MARKETS_PROGRAM_ID,
+        accounts: markets_accounts::CreatorRevenue { market }.to_account_metas(None),
+        data: markets_ix::CreatorRevenue {}.data(),
+    }
+}
+
+fn build_claim_creator_fees_ix(
+    creator: LegacyPubkey,
+    market: LegacyPubkey,
+    config: LegacyPubkey,
+    usdc_mint: LegacyPubkey,
+    vault: LegacyPubkey,
+    creator_usdc: LegacyPubkey,
+) -> LegacyInstruction {
+    LegacyInstruction {
+        program_id: WZRD_MARKETS_PROGRAM_ID,
+        accounts: markets_accounts::ClaimCreatorFees {
+            creator,
+            market,
+            config,
+            usdc_mint,
+            vault,
+            creator_usdc,
+            usdc_token_program: spl_token_2022::id(),
+        }
+        .to_account_metas(None),
+        data: markets_ix::ClaimCreatorFees {}.data(),
+    }
+}
+
+fn build_push_creator_fees_ix(
+    admin: LegacyPubkey,
+    config: LegacyPubkey,
+    market: LegacyPubkey,
+    usdc_mint: LegacyPubkey,
+    vault: LegacyPubkey,
+    creator: LegacyPubkey,
+    creator_usdc: LegacyPubkey,
+) -> LegacyInstruction {
+    LegacyInstruction {
+        program_id: WZRD_MARKETS_PROGRAM_ID,
+        accounts: markets_accounts::PushCreatorFees {
+            admin,
+            config,
+            market,
+            usdc_mint,
+            vault,
+            creator,
+            creator_usdc,
+            usdc_token_program: spl_token_2022::id(),
+        }
+        .to_account_metas(None),
+        data: markets_ix::PushCreatorFees {}.data(),
+    }
+}
+
 fn build_resolve_override_ix(
     resolver_multisig: LegacyPubkey,
     config: LegacyPubkey,
@@ -833,12 +895,23 @@ fn markets_two_leaf_tree(
     market_id: u64,
     window_id: u64,
     outcome_a: u8,
+) -> ([u8; 32], Vec<[u8; 32]>) {
+    markets_two_leaf_tree_for_metric(market_id, window_id, METRIC, outcome_a)
+}
+
+/// `markets_two_leaf_tree`, with both leaves bound to `metric` instead of
+/// `METRIC`.
+fn markets_two_leaf_tree_for_metric(
+    market_id: u64,
+    window_id: u64,
+    metric: u8,
+    outcome_a: u8,
 ) -> ([u8; 32], Vec<[u8; 32]>) {
     let leaf_a = MarketsResolutionLeafV1::new(
         market_id,
         STREAMER_REF,
         window_id,
-        METRIC,
+        metric,
         OBSERVED_VALUE,
         outcome_a,
     );
@@ -846,7 +919,7 @@ fn markets_two_leaf_tree(
         market_id,
         SIBLING_STREAMER_REF,
         window_id,
-        METRIC,
+        metric,
         SIBLING_VALUE,
         resolution::outcome::NO,
     );
@@ -911,6 +984,34 @@ fn future_deadline_slot() -> u64 {
 /// depositor holds `SET_AMOUNT` YES + `SET_AMOUNT` NO and
 /// `DEPOSITOR_USDC_FUNDING - SET_AMOUNT` USDC.
 fn setup_funded(resolution_root: [u8; 32], dispute_window_slots: u64, deadline: u64) -> Fixture {
+    setup_funded_with_creator_fee(resolution_root, dispute_window_slots, deadline, 0)
+}
+
+/// `setup_funded`, but the market is created with `creator_fee_bps` (the admin
+/// is the creator).
+fn setup_funded_with_creator_fee(
+    resolution_root: [u8; 32],
+    dispute_window_slots: u64,
+    deadline: u64,
+    creator_fee_bps: u16,
+) -> Fixture {
+    setup_funded_with_metric(
+        resolution_root,
+        dispute_window_slots,
+        deadline,
+        creator_fee_bps,
+        METRIC,
+    )
+}
+
+/// `setup_funded_with_creator_fee`, but the market resolves on `metric`.
+fn setup_funded_with_metric(
+    resolution_root: [u8; 32],
+    dispute_window_slots: u64,
+    deadline: u64,
+    creator_fee_bps: u16,
+    metric: u8,
+) -> Fixture {
     let mut svm = LiteSVM::new();
     load_wzrd_markets_program(&mut svm).expect("load wzrd-markets program");
     load_token_2022_program(&mut svm);
@@ -1001,12 +1102,13 @@ fn setup_funded(resolution_root: [u8; 32], dispute_window_slots: u64, deadline:
             market,
             MARKET_ID,
             STREAMER_REF,
-            METRIC,
+            metric,
             1_000,
             resolution_root,
             42,
             deadline,
             dispute_window_slots,
+            creator_fee_bps,
         )],
     );
 
@@ -1602,8 +1704,10 @@ fn func_publish_root_happy_and_idempotent() {
     let mut f = setup_funded(root, MIN_DISPUTE_WINDOW, future_deadline_slot());
     let (attention_root, _) = attention_root_pda(WINDOW_ID);
 
-    // Publish a (separate, discoverability-only) root for the window.
+    // Publish a (separate, discoverability-only) root for the window, committed
+    // together with the hash of the dataset it was built from.
     let published_root = [0x42u8; 32];
+    let dataset_hash = [0x5Au8; 32];
     send_tx(
         &mut f.svm,
         &[&f.publisher],
@@ -1614,6 +1718,7 @@ fn func_publish_root_happy_and_idempotent() {
             attention_root,
             WINDOW_ID,
             published_root,
+            dataset_hash,
             2,
             MARKETS_RESOLUTION_LEAF_SCHEMA_V1,
         )],
@@ -1622,6 +1727,10 @@ fn func_publish_root_happy_and_idempotent() {
     let stored: AttentionRoot = read_anchor_account(&f.svm, &attention_root);
     assert_eq!(stored.window_id, WINDOW_ID);
     assert_eq!(stored.merkle_root, published_root);
+    assert_eq!(
+        stored.dataset_hash, dataset_hash,
+        "dataset hash round-trips"
+    );
     assert_eq!(stored.leaf_count, 2);
     assert_eq!(stored.schema_version, MARKETS_RESOLUTION_LEAF_SCHEMA_V1);
     assert_eq!(
@@ -1646,6 +1755,7 @@ fn func_publish_root_happy_and_idempotent() {
             attention_root,
             WINDOW_ID,
             [0x43u8; 32],
+            [0x5Bu8; 32],
             2,
             MARKETS_RESOLUTION_LEAF_SCHEMA_V1,
         )],
@@ -1671,6 +1781,7 @@ fn func_publish_root_validation() {
             attention_root,
             WINDOW_ID,
             [0u8; 32],
+            [0x5Au8; 32],
             1,
             MARKETS_RESOLUTION_LEAF_SCHEMA_V1,
         )],
@@ -1689,6 +1800,7 @@ fn func_publish_root_validation() {
             attention_root,
             WINDOW_ID,
             [0x42u8; 32],
+            [0x5Au8; 32],
             1,
             MARKETS_RESOLUTION_LEAF_SCHEMA_V1 + 7,
         )],
@@ -2163,6 +2275,89 @@ fn func_sweep_residual_supply_guard() {
     );
 }
 
+/// §9.6b — residual dust left in the vault after every holder has settled is
+/// recoverable to the treasury by `sweep_residual`. The USDC mint is fee-free
+/// (TransferFeeConfig is rejected at config init), so the sweep moves the full
+/// residual with nothing withheld.
+#[test]
+fn func_sweep_residual_recovers_dust_after_settlement() {
+    const DUST: u64 = 37;
+    let (root, proof) = markets_two_leaf_tree(MARKET_ID, WINDOW_ID, resolution::outcome::YES);
+    let mut f = setup_funded(root, MIN_DISPUTE_WINDOW, future_deadline_slot());
+
+    send_tx(
+        &mut f.svm,
+        &[&f.publisher],
+        &[build_resolve_market_ix(
+            legacy_from_signer(&f.publisher),
+            f.config,
+            f.market,
+            WINDOW_ID,
+            OBSERVED_VALUE,
+            resolution::outcome::YES,
+            proof,
+        )],
+    );
+    let market: Market = read_anchor_account(&f.svm, &f.market);
+    f.svm.warp_to_slot(market.settle_unlock_slot + 1);
+    send_tx(
+        &mut f.svm,
+        &[&f.depositor],
+        &[build_settle_ix(
+            legacy_from_signer(&f.depositor),
+            f.market,
+            f.config,
+            f.usdc_mint,
+            f.yes_mint,
+            f.no_mint,
+            f.vault,
+            f.depositor_usdc,
+            f.depositor_yes,
+            f.depositor_no,
+            SET_AMOUNT,
+        )],
+    );
+
+    // Stray USDC lands in the vault after the last settle (rounding residue /
+    // mistaken transfer); no holder has a claim on it.
+    let stray = spl_token_2022::instruction::transfer_checked(
+        &spl_token_2022::id(),
+        &f.depositor_usdc,
+        &f.usdc_mint,
+        &f.vault,
+        &legacy_from_signer(&f.depositor),
+        &[],
+        DUST,
+        USDC_DECIMALS,
+    )
+    .unwrap();
+    send_tx(&mut f.svm, &[&f.depositor], &[stray]);
+    assert_eq!(read_token_balance(&f.svm, &f.vault), DUST);
+
+    let treasury = create_ata(
+        &mut f.svm,
+        &f.admin,
+        &legacy_from_signer(&f.admin),
+        &f.usdc_mint,
+    );
+    send_tx(
+        &mut f.svm,
+        &[&f.admin],
+        &[build_sweep_residual_ix(
+            legacy_from_signer(&f.admin),
+            f.config,
+            f.market,
+            f.usdc_mint,
+            f.yes_mint,
+            f.no_mint,
+            f.vault,
+            treasury,
+        )],
+    );
+    assert_eq!(read_token_balance(&f.svm, &f.vault), 0);
+    assert_eq!(read_token_balance(&f.svm, &treasury), DUST);
+}
+
 /// §9.7 — publisher allow-list is enforced by resolve_market: a signer NOT on the
 /// allow-list is rejected (UnauthorizedPublisher), and remove_publisher revokes a
 /// previously-allowed publisher.
@@ -2276,6 +2471,46 @@ fn func_allowlist_management_guards() {
     assert_markets_error(zero, MarketsError::InvalidPubkey);
 }
 
+/// Every defined `MarketMetric` resolves: the leaf is bound to the market's own
+/// metric and the `observed_value >= target` rule applies to each of them.
+#[test]
+fn func_resolve_each_metric() {
+    for metric in [
+        MarketMetric::AVG_VIEWERS,
+        MarketMetric::PEAK_VIEWERS,
+        MarketMetric::HOURS_WATCHED,
+        MarketMetric::ENGAGEMENT_SCORE,
+    ] {
+        let (root, proof) = markets_two_leaf_tree_for_metric(
+            MARKET_ID,
+            WINDOW_ID,
+            metric,
+            resolution::outcome::YES,
+        );
+        let mut f =
+            setup_funded_with_metric(root, MIN_DISPUTE_WINDOW, future_deadline_slot(), 0, metric);
+
+        send_tx(
+            &mut f.svm,
+            &[&f.publisher],
+            &[build_resolve_market_ix(
+                legacy_from_signer(&f.publisher),
+                f.config,
+                f.market,
+                WINDOW_ID,
+                OBSERVED_VALUE,
+                resolution::outcome::YES,
+                proof,
+            )],
+        );
+
+        let market: Market = read_anchor_account(&f.svm, &f.market);
+        assert_eq!(market.metric, metric);
+        assert!(market.resolved, "metric {metric} resolves");
+        assert_eq!(market.outcome, resolution::outcome::YES);
+    }
+}
+
 /// Regression: resolve cannot run twice (MarketAlreadyResolved) and cannot run
 /// after the deadline (ResolutionDeadlinePassed).
 #[test]
@@ -2420,6 +2655,7 @@ fn func_l01_deadline_too_far_rejected() {
             1,
             u64::MAX,
             MIN_DISPUTE_WINDOW,
+            0,
         )],
     );
     assert_markets_error(bad, MarketsError::DeadlineTooFar);
@@ -2441,6 +2677,7 @@ fn func_l01_deadline_too_far_rejected() {
             1,
             future_deadline_slot(),
             MIN_DISPUTE_WINDOW,
+            0,
         )],
     );
     let mkt1: Market = read_anchor_account(&f.svm, &market1);
@@ -2508,6 +2745,7 @@ fn func_l02_resolve_requires_tokens_initialized() {
             1,
             future_deadline_slot(),
             MIN_DISPUTE_WINDOW,
+            0,
         )],
     );
     let mkt2: Market = read_anchor_account(&svm2, &market2);
@@ -2673,3 +2911,312 @@ fn func_l05_override_resets_dispute_extended() {
         "post-override extension consumed the allowance"
     );
 }
+
+/// Creator fee: a market created with `creator_fee_bps` withholds that share of
+/// every settle payout in the vault (accrued on the market) and the creator
+/// later claims it. Settle needs no creator account, a sweep leaves the accrued
+/// fees alone, and only the creator can claim.
+#[test]
+fn func_creator_fee_accrues_and_is_claimed() {
+    const CREATOR_FEE_BPS: u16 = 250; // 2.5%
+    let (root, _) = markets_two_leaf_tree(MARKET_ID, WINDOW_ID, resolution::outcome::YES);
+    let mut f = setup_funded_with_creator_fee(
+        root,
+        MIN_DISPUTE_WINDOW,
+        future_deadline_slot(),
+        CREATOR_FEE_BPS,
+    );
+    let market: Market = read_anchor_account(&f.svm, &f.market);
+    assert_eq!(market.creator_fee_bps, CREATOR_FEE_BPS);
+
+    f.resolve_yes();
+    let market: Market = read_anchor_account(&f.svm, &f.market);
+    f.svm.warp_to_slot(market.settle_unlock_slot + 1);
+
+    let usdc_before = read_token_balance(&f.svm, &f.depositor_usdc);
+    let mut expected_fees = 0u64;
+    let mut settled = 0u64;
+    for part in [400_000_000u64, 350_000_000, 250_000_000] {
+        f.svm.expire_blockhash();
+        send_tx(
+            &mut f.svm,
+            &[&f.depositor],
+            &[build_settle_ix(
+                legacy_from_signer(&f.depositor),
+                f.market,
+                f.config,
+                f.usdc_mint,
+                f.yes_mint,
+                f.no_mint,
+                f.vault,
+                f.depositor_usdc,
+                f.depositor_yes,
+                f.depositor_no,
+                part,
+            )],
+        );
+        expected_fees += part * CREATOR_FEE_BPS as u64 / 10_000;
+        settled += part;
+        let market: Market = read_anchor_account(&f.svm, &f.market);
+        assert_eq!(
+            market.creator_fees_accrued, expected_fees,
+            "creator accrues the fee on every settle"
+        );
+        assert_eq!(
+            read_token_balance(&f.svm, &f.vault),
+            SET_AMOUNT - settled + expected_fees,
+            "vault keeps the winners' remainder plus the accrued fees"
+        );
+    }
+
+    assert_eq!(expected_fees, 25_000_000, "2.5% of SET_AMOUNT");
+    assert_eq!(
+        read_token_balance(&f.svm, &f.depositor_usdc) - usdc_before,
+        SET_AMOUNT - expected_fees,
+        "settler receives the payout net of the creator fee"
+    );
+
+    // The admin created the market, so it is the creator; its ATA doubles as
+    // the sweep treasury.
+    let creator_usdc = create_ata(
+        &mut f.svm,
+        &f.admin,
+        &legacy_from_signer(&f.admin),
+        &f.usdc_mint,
+    );
+
+    // Winning supply is 0, but the accrued fees are not dust: a sweep leaves them.
+    f.svm.expire_blockhash();
+    send_tx(
+        &mut f.svm,
+        &[&f.admin],
+        &[build_sweep_residual_ix(
+            legacy_from_signer(&f.admin),
+            f.config,
+            f.market,
+            f.usdc_mint,
+            f.yes_mint,
+            f.no_mint,
+            f.vault,
+            creator_usdc,
+        )],
+    );
+    assert_eq!(read_token_balance(&f.svm, &f.vault), expected_fees);
+    assert_eq!(
+        read_token_balance(&f.svm, &creator_usdc),
+        0,
+        "no dust swept"
+    );
+
+    // Anyone but the creator → Unauthorized.
+    f.svm.expire_blockhash();
+    let stranger = try_send_tx(
+        &mut f.svm,
+        &[&f.depositor],
+        &[build_claim_creator_fees_ix(
+            legacy_from_signer(&f.depositor),
+            f.market,
+            f.config,
+            f.usdc_mint,
+            f.vault,
+            f.depositor_usdc,
+        )],
+    );
+    assert_markets_error(stranger, MarketsError::Unauthorized);
+
+    let claim = build_claim_creator_fees_ix(
+        legacy_from_signer(&f.admin),
+        f.market,
+        f.config,
+        f.usdc_mint,
+        f.vault,
+        creator_usdc,
+    );
+    f.svm.expire_blockhash();
+    send_tx(&mut f.svm, &[&f.admin], &[claim.clone()]);
+    assert_eq!(read_token_balance(&f.svm, &creator_usdc), expected_fees);
+    assert_eq!(read_token_balance(&f.svm, &f.vault), 0, "vault drained");
+    let market: Market = read_anchor_account(&f.svm, &f.market);
+    assert_eq!(market.creator_fees_accrued, 0);
+
+    // Nothing left → NoCreatorFeesAccrued.
+    f.svm.expire_blockhash();
+    let again = try_send_tx(&mut f.svm, &[&f.admin], &[claim]);
+    assert_markets_error(again, MarketsError::NoCreatorFeesAccrued);
+}
+
+/// An absent creator cannot pin a market open: the admin pushes the accrued
+/// fees to the creator's own USDC ATA (never an admin-chosen account).
+#[test]
+fn func_admin_pushes_unclaimed_creator_fees() {
+    const CREATOR_FEE_BPS: u16 = 250; // 2.5%
+    let (root, _) = markets_two_leaf_tree(MARKET_ID, WINDOW_ID, resolution::outcome::YES);
+    let mut f = setup_funded_with_creator_fee(
+        root,
+        MIN_DISPUTE_WINDOW,
+        future_deadline_slot(),
+        CREATOR_FEE_BPS,
+    );
+    f.resolve_yes();
+    let market: Market = read_anchor_account(&f.svm, &f.market);
+    f.svm.warp_to_slot(market.settle_unlock_slot + 1);
+    send_tx(
+        &mut f.svm,
+        &[&f.depositor],
+        &[build_settle_ix(
+            legacy_from_signer(&f.depositor),
+            f.market,
+            f.config,
+            f.usdc_mint,
+            f.yes_mint,
+            f.no_mint,
+            f.vault,
+            f.depositor_usdc,
+            f.depositor_yes,
+            f.depositor_no,
+            SET_AMOUNT,
+        )],
+    );
+    let expected_fees = SET_AMOUNT * CREATOR_FEE_BPS as u64 / 10_000;
+
+    // The admin created the market, so it is also the creator.
+    let creator = legacy_from_signer(&f.admin);
+    let creator_usdc = create_ata(&mut f.svm, &f.admin, &creator, &f.usdc_mint);
+
+    // Only the admin may push.
+    f.svm.expire_blockhash();
+    let stranger = try_send_tx(
+        &mut f.svm,
+        &[&f.depositor],
+        &[build_push_creator_fees_ix(
+            legacy_from_signer(&f.depositor),
+            f.config,
+            f.market,
+            f.usdc_mint,
+            f.vault,
+            creator,
+            creator_usdc,
+        )],
+    );
+    assert_markets_error(stranger, MarketsError::Unauthorized);
+
+    // The destination must belong to the market's creator.
+    f.svm.expire_blockhash();
+    let redirected = try_send_tx(
+        &mut f.svm,
+        &[&f.admin],
+        &[build_push_creator_fees_ix(
+            legacy_from_signer(&f.admin),
+            f.config,
+            f.market,
+            f.usdc_mint,
+            f.vault,
+            legacy_from_signer(&f.depositor),
+            f.depositor_usdc,
+        )],
+    );
+    assert_markets_error(redirected, MarketsError::AccountMismatch);
+
+    let push = build_push_creator_fees_ix(
+        legacy_from_signer(&f.admin),
+        f.config,
+        f.market,
+        f.usdc_mint,
+        f.vault,
+        creator,
+        creator_usdc,
+    );
+    f.svm.expire_blockhash();
+    send_tx(&mut f.svm, &[&f.admin], &[push.clone()]);
+    assert_eq!(read_token_balance(&f.svm, &creator_usdc), expected_fees);
+    let market: Market = read_anchor_account(&f.svm, &f.market);
+    assert_eq!(market.creator_fees_accrued, 0, "close_market is unblocked");
+
+    f.svm.expire_blockhash();
+    let again = try_send_tx(&mut f.svm, &[&f.admin], &[push]);
+    assert_markets_error(again, MarketsError::NoCreatorFeesAccrued);
+}
+
+/// `creator_revenue` reports the creator's lifetime fee revenue: zero before any
+/// settle, the sum of both settles' fees after, and unchanged once claimed.
+#[test]
+fn func_creator_revenue_view_tracks_fees() {
+    const CREATOR_FEE_BPS: u16 = 250; // 2.5%
+    let (root, _) = markets_two_leaf_tree(MARKET_ID, WINDOW_ID, resolution::outcome::YES);
+    let mut f = setup_funded_with_creator_fee(
+        root,
+        MIN_DISPUTE_WINDOW,
+        future_deadline_slot(),
+        CREATOR_FEE_BPS,
+    );
+    f.resolve_yes();
+    let market: Market = read_anchor_account(&f.svm, &f.market);
+    f.svm.warp_to_slot(market.settle_unlock_slot + 1);
+    let creator_usdc = create_ata(
+        &mut f.svm,
+        &f.admin,
+        &legacy_from_signer(&f.admin),
+        &f.usdc_mint,
+    );
+
+    let creator_revenue = |f: &mut Fixture| {
+        f.svm.expire_blockhash();
+        let meta = send_tx(
+            &mut f.svm,
+            &[&f.depositor],
+            &[build_creator_revenue_ix(f.market)],
+        );
+        let data = meta.return_data.data;
+        assert_eq!(data.len(), 8, "creator_revenue returns a u64");
+        u64::from_le_bytes(data.try_into().unwrap())
+    };
+    assert_eq!(creator_revenue(&mut f), 0, "nothing accrued before settle");
+
+    let mut expected = 0u64;
+    for part in [400_000_000u64, 600_000_000] {
+        f.svm.expire_blockhash();
+        send_tx(
+            &mut f.svm,
+            &[&f.depositor],
+            &[build_settle_ix(
+                legacy_from_signer(&f.depositor),
+                f.market,
+                f.config,
+                f.usdc_mint,
+                f.yes_mint,
+                f.no_mint,
+                f.vault,
+                f.depositor_usdc,
+                f.depositor_yes,
+                f.depositor_no,
+                part,
+            )],
+        );
+        expected += part * CREATOR_FEE_BPS as u64 / 10_000;
+        assert_eq!(creator_revenue(&mut f), expected);
+    }
+
+    assert_eq!(expected, 25_000_000, "2.5% of SET_AMOUNT");
+
+    f.svm.expire_blockhash();
+    send_tx(
+        &mut f.svm,
+        &[&f.admin],
+        &[build_claim_creator_fees_ix(
+            legacy_from_signer(&f.admin),
+            f.market,
+            f.config,
+            f.usdc_mint,
+            f.vault,
+            creator_usdc,
+        )],
+    );
+    assert_eq!(read_token_balance(&f.svm, &creator_usdc), expected);
+    assert_eq!(
+        creator_revenue(&mut f),
+        expected,
+        "a claim does not reset the lifetime total"
+    );
+    let market: Market = read_anchor_account(&f.svm, &f.market);
+    assert_eq!(market.creator_fees_accrued, 0, "the claimable balance does");
+}
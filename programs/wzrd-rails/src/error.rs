@@ -66,6 +66,18 @@ pub enum RailsError {
 
     #[msg("Stake pool account has an unexpected size; realloc migration expects the legacy 61-byte layout.")]
     StakePoolUnexpectedSize = 19,
+
+    #[msg("Rescue refused: the protocol CCM mint cannot be moved via rescue_tokens.")]
+    RescueProtocolMint = 20,
+
+    #[msg("Proposed admin must differ from the current admin.")]
+    InvalidAdminProposal = 21,
+
+    #[msg("Rescue amount must be greater than zero.")]
+    RescueAmountZero = 22,
+
+    #[msg("An admin proposal is already pending; cancel it first.")]
+    ProposalPending = 23,
 }
 
 #[error_code]
@@ -144,4 +156,13 @@ pub enum ListenPayoutError {
 
     #[msg("Cumulative claimed amount would exceed the published window total")]
     ExceedsWindowTotal = 124,
+
+    #[msg("Listen payout vault balance is below the claimed amount")]
+    VaultInsufficient = 125,
+
+    #[msg("relayer_fee exceeds MAX_RELAYER_FEE_BPS of the claimed amount")]
+    RelayerFeeTooHigh = 126,
+
+    #[msg("relayer_ata must be a CCM token account owned by the relayer (payer)")]
+    RelayerAccountMismatch = 127,
 }
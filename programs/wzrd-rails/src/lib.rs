@@ -50,7 +50,8 @@ pub mod wzrd_rails {
     /// Initialize the program's global config. One-time, per deployment.
     ///
     /// The signer becomes the initial admin. Admin can be transferred later via
-    /// `set_admin` (e.g., to a Squads V4 vault PDA once production multisig is set up).
+    /// `propose_admin` / `accept_admin` (e.g., to a Squads V4 vault PDA once
+    /// production multisig is set up).
     ///
     /// Preconditions:
     ///   - Config PDA must not already exist (init constraint below enforces).
@@ -90,7 +91,76 @@ pub mod wzrd_rails {
         Ok(())
     }
 
-    /// Transfer admin authority to a new pubkey.
+    /// Step 1 of the two-step admin handover: current admin proposes a successor.
+    ///
+    /// Nothing changes until the proposed key signs `accept_admin`, so a typo'd
+    /// or unsignable key cannot lock the program out. One proposal at a time;
+    /// `cancel_admin_proposal` clears it.
+    ///
+    /// Preconditions: current admin signs; no proposal pending; new_admin is
+    /// non-zero and differs from the current admin.
+    pub fn propose_admin(ctx: Context<ProposeAdmin>, new_admin: Pubkey) -> Result<()> {
+        require!(
+            new_admin != Pubkey::default(),
+            ListenPayoutError::AdminPubkeyMustBeNonZero
+        );
+        require!(
+            new_admin != ctx.accounts.config.admin,
+            RailsError::InvalidAdminProposal
+        );
+        // Accept/cancel close the proposal, so a live account is always pending.
+        require!(
+            ctx.accounts.proposal.pending_admin == Pubkey::default(),
+            RailsError::ProposalPending
+        );
+        let slot = Clock::get()?.slot;
+        let proposal = &mut ctx.accounts.proposal;
+        proposal.pending_admin = new_admin;
+        proposal.proposed_at_slot = slot;
+        proposal.bump = ctx.bumps.proposal;
+        emit!(AdminProposed {
+            config: ctx.accounts.config.key(),
+            current_admin: ctx.accounts.config.admin,
+            pending_admin: new_admin,
+            slot,
+        });
+        Ok(())
+    }
+
+    /// Step 2 of the two-step admin handover: the proposed admin accepts.
+    ///
+    /// Preconditions: signer == proposal.pending_admin.
+    /// Postconditions: config.admin = pending_admin; proposal closed (rent back
+    /// to the outgoing admin).
+    pub fn accept_admin(ctx: Context<AcceptAdmin>) -> Result<()> {
+        let slot = Clock::get()?.slot;
+        let config_key = ctx.accounts.config.key();
+        let old_admin = ctx.accounts.config.admin;
+        let new_admin = ctx.accounts.pending_admin.key();
+        ctx.accounts.config.admin = new_admin;
+        emit!(AdminChanged {
+            config: config_key,
+            old_admin,
+            new_admin,
+            slot,
+        });
+        Ok(())
+    }
+
+    /// Withdraw a pending admin proposal. Admin-only.
+    pub fn cancel_admin_proposal(ctx: Context<CancelAdminProposal>) -> Result<()> {
+        emit!(AdminProposalCancelled {
+            config: ctx.accounts.config.key(),
+            pending_admin: ctx.accounts.proposal.pending_admin,
+            slot: Clock::get()?.slot,
+        });
+        Ok(())
+    }
+
+    /// Transfer admin authority to a new pubkey in one step.
+    ///
+    /// Emergency-only: compiled in solely with the `emergency-admin` feature.
+    /// Routine rotation goes through `propose_admin` / `accept_admin`.
     ///
     /// Use cases:
     ///   - Migrate from deployer key → Squads V4 vault PDA at production go-live
@@ -99,6 +169,7 @@ pub mod wzrd_rails {
     ///
     /// Preconditions: current admin signs.
     /// Postconditions: config.admin = new_admin.
+    #[cfg(feature = "emergency-admin")]
     pub fn set_admin(ctx: Context<AdminOnly>, new_admin: Pubkey) -> Result<()> {
         // Per audit finding M-3 / EZ-7: reject Pubkey::default() — single-key
         // typo to all-zeros permanently retires the admin role and is only
@@ -122,6 +193,30 @@ pub mod wzrd_rails {
         Ok(())
     }
 
+    /// Rotate the treasury CCM token account recorded in config. Admin-only.
+    ///
+    /// Unlike `initialize_config`, the new treasury is validated on-chain: it
+    /// must be the canonical Token-2022 ATA of `treasury_owner` for
+    /// `config.ccm_mint`. No instruction reads `treasury_ccm_ata` yet; it is
+    /// the off-chain revenue-router keeper's funding source of record.
+    ///
+    /// Preconditions: admin signs; `treasury` is the canonical CCM ATA.
+    /// Postconditions: config.treasury_ccm_ata = treasury.key().
+    pub fn set_treasury(ctx: Context<SetTreasury>) -> Result<()> {
+        let slot = Clock::get()?.slot;
+        let config_key = ctx.accounts.config.key();
+        let new_treasury = ctx.accounts.treasury.key();
+        let old_treasury = ctx.accounts.config.treasury_ccm_ata;
+        ctx.accounts.config.treasury_ccm_ata = new_treasury;
+        emit!(TreasuryUpdated {
+            config: config_key,
+            old_treasury,
+            new_treasury,
+            slot,
+        });
+        Ok(())
+    }
+
     /// Change a pool's reward emission rate. Admin-only.
     ///
     /// Semantic: emissions are measured in CCM base units per slot. At 400ms/slot,
@@ -418,8 +513,8 @@ pub mod wzrd_rails {
 
     /// Rotate the single Listen payout admin.
     ///
-    /// Named `set_payout_admin` because `wzrd-rails` already has a global
-    /// `set_admin` IX for the base rails config.
+    /// Named `set_payout_admin` because the base rails config admin is rotated
+    /// separately via `propose_admin` / `accept_admin`.
     pub fn set_payout_admin(ctx: Context<SetPayoutAdmin>, args: SetPayoutAdminArgs) -> Result<()> {
         // Per audit finding M-3 / EZ-7: reject Pubkey::default() — typo
         // permanently bricks the payout admin role.
@@ -548,6 +643,51 @@ pub mod wzrd_rails {
         Ok(())
     }
 
+    /// Rescue wrong-mint tokens stuck in a config-owned token account. Admin-only.
+    ///
+    /// Airdrops and operator mistakes can land foreign mints in token accounts
+    /// whose authority is the config PDA, where nothing else can move them.
+    /// This IX lets the admin sweep such balances to an account of their choice.
+    ///
+    /// Preconditions:
+    ///   - admin signer matches config.admin
+    ///   - `mint` is NOT config.ccm_mint — CCM held by the program backs stakes,
+    ///     rewards and compensation and is never rescuable
+    ///   - `source` is a `mint` token account whose authority is the config PDA
+    ///   - `destination` is a `mint` token account (any owner)
+    ///   - amount > 0
+    ///
+    /// Postconditions: `amount` of `mint` moved from `source` to `destination`.
+    pub fn rescue_tokens(ctx: Context<RescueTokens>, amount: u64) -> Result<()> {
+        require!(amount > 0, RailsError::RescueAmountZero);
+
+        let slot = Clock::get()?.slot;
+        let config_ai = ctx.accounts.config.to_account_info();
+        let signer_seeds: &[&[&[u8]]] = &[&[CONFIG_SEED, &[ctx.accounts.config.bump]]];
+        let transfer_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.source.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                to: ctx.accounts.destination.to_account_info(),
+                authority: config_ai,
+            },
+            signer_seeds,
+        );
+        token_interface::transfer_checked(transfer_ctx, amount, ctx.accounts.mint.decimals)?;
+
+        emit!(TokensRescued {
+            config: ctx.accounts.config.key(),
+            admin: ctx.accounts.admin.key(),
+            mint: ctx.accounts.mint.key(),
+            source: ctx.accounts.source.key(),
+            destination: ctx.accounts.destination.key(),
+            amount,
+            slot,
+        });
+        Ok(())
+    }
+
     /// Initialize a new stake pool. Admin-only.
     ///
     /// Day 1 creates pool_id = 0 (the global pool). Future per-channel pools
@@ -1078,77 +1218,160 @@ pub mod wzrd_rails {
         ctx: Context<ClaimListenPayout>,
         args: ClaimListenPayoutArgs,
     ) -> Result<()> {
-        let auth_cfg = &ctx.accounts.authority_config;
-        let win = &mut ctx.accounts.payout_window;
         let leaf = &args.leaf;
+        let verified = verify_listen_payout_claim(
+            &ctx.accounts.payout_window,
+            &ctx.accounts.authority_config,
+            &ctx.accounts.claimer.key(),
+            leaf,
+            &args.proof,
+            ctx.accounts.listen_payout_vault.amount,
+        )?;
 
-        require!(!auth_cfg.paused, ListenPayoutError::Paused);
-        require!(
-            leaf.window_id == win.window_id,
-            ListenPayoutError::LeafWindowMismatch
-        );
-        require!(
-            leaf.schema_version == win.schema_version,
-            ListenPayoutError::SchemaVersionMismatch
-        );
-        require!(
-            leaf.schema_version == LISTEN_PAYOUT_LEAF_SCHEMA_V1,
-            ListenPayoutError::SchemaVersionMismatch
-        );
-        require!(
-            ctx.accounts.claimer.key() == leaf.wallet_pubkey,
-            ListenPayoutError::ClaimerWalletMismatch
-        );
-        require!(
-            leaf.leaf_index < win.leaf_count,
-            ListenPayoutError::LeafIndexOutOfBounds
-        );
+        let bump = ctx.accounts.vault_config.vault_authority_bump;
+        let signer_seeds: &[&[&[u8]]] = &[&[LISTEN_PAYOUT_VAULT_AUTHORITY_SEED, &[bump]]];
+        token_interface::transfer_checked(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.listen_payout_vault.to_account_info(),
+                    mint: ctx.accounts.ccm_mint.to_account_info(),
+                    to: ctx.accounts.claimer_ata.to_account_info(),
+                    authority: ctx.accounts.vault_authority.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            leaf.amount_ccm,
+            ctx.accounts.ccm_mint.decimals,
+        )?;
 
-        let byte_idx = (leaf.leaf_index as usize) / 8;
-        let bit_idx = (leaf.leaf_index as usize) % 8;
-        require!(
-            byte_idx < win.claim_bitmap.len(),
-            ListenPayoutError::LeafIndexOutOfBounds
-        );
-        let bit_mask = 1u8 << bit_idx;
-        require!(
-            win.claim_bitmap[byte_idx] & bit_mask == 0,
-            ListenPayoutError::AlreadyClaimed
-        );
-        require!(
-            args.proof.len() <= MAX_PROOF_LEN,
-            ListenPayoutError::ProofTooLong
-        );
+        // Consume the leaf only once the transfer has succeeded. A failing CPI
+        // already rolls back the whole IX, but keeping the write after the
+        // interaction makes that ordering explicit rather than incidental.
+        verified.consume(&mut ctx.accounts.payout_window);
 
-        let mut current = leaf.hash();
-        for sibling in args.proof.iter() {
-            current = listen_payout_node_hash_v1(&current, sibling);
-        }
+        emit!(ListenPayoutClaimed {
+            window_id: leaf.window_id,
+            leaf_index: leaf.leaf_index,
+            wallet: ctx.accounts.claimer.key(),
+            amount_ccm: leaf.amount_ccm,
+            pool_id: leaf.pool_id,
+            allocation_id: leaf.allocation_id,
+            claimed_at_slot: Clock::get()?.slot,
+            recipient: ctx.accounts.claimer.key(),
+            leaf_hash: verified.leaf_hash,
+        });
+
+        Ok(())
+    }
+
+    /// `claim_listen_payout`, delivering to `recipient`'s Token-2022 ATA
+    /// instead of the claimer's (custodial / smart-wallet flows).
+    ///
+    /// The leaf is still verified against the signing claimer, so only the
+    /// allocation owner can redirect its payout. The claimer pays the rent if
+    /// the recipient ATA has to be created.
+    pub fn claim_listen_payout_to(
+        ctx: Context<ClaimListenPayoutTo>,
+        args: ClaimListenPayoutArgs,
+    ) -> Result<()> {
+        let leaf = &args.leaf;
+        let verified = verify_listen_payout_claim(
+            &ctx.accounts.payout_window,
+            &ctx.accounts.authority_config,
+            &ctx.accounts.claimer.key(),
+            leaf,
+            &args.proof,
+            ctx.accounts.listen_payout_vault.amount,
+        )?;
+
+        let bump = ctx.accounts.vault_config.vault_authority_bump;
+        let signer_seeds: &[&[&[u8]]] = &[&[LISTEN_PAYOUT_VAULT_AUTHORITY_SEED, &[bump]]];
+        token_interface::transfer_checked(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.listen_payout_vault.to_account_info(),
+                    mint: ctx.accounts.ccm_mint.to_account_info(),
+                    to: ctx.accounts.recipient_ata.to_account_info(),
+                    authority: ctx.accounts.vault_authority.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            leaf.amount_ccm,
+            ctx.accounts.ccm_mint.decimals,
+        )?;
+
+        verified.consume(&mut ctx.accounts.payout_window);
+
+        emit!(ListenPayoutClaimed {
+            window_id: leaf.window_id,
+            leaf_index: leaf.leaf_index,
+            wallet: ctx.accounts.claimer.key(),
+            amount_ccm: leaf.amount_ccm,
+            pool_id: leaf.pool_id,
+            allocation_id: leaf.allocation_id,
+            claimed_at_slot: Clock::get()?.slot,
+            recipient: ctx.accounts.recipient.key(),
+            leaf_hash: verified.leaf_hash,
+        });
+
+        Ok(())
+    }
+
+    /// Gasless variant of `claim_listen_payout`.
+    ///
+    /// The allocation owner (`claimer`) still signs and the proof still binds
+    /// to them, but a relayer signs as `payer`: it pays the transaction fee and
+    /// the rent of the claimer's ATA if that has to be created. In return it
+    /// takes `args.relayer_fee` (at most `MAX_RELAYER_FEE_BPS` of the leaf) into
+    /// its own CCM account; the remainder goes to the claimer's ATA.
+    ///
+    /// Preconditions: as `claim_listen_payout`, plus `relayer_ata` is a CCM
+    /// token account owned by `payer`.
+    pub fn claim_listen_payout_relayed(
+        ctx: Context<ClaimListenPayoutRelayed>,
+        args: ClaimListenPayoutRelayedArgs,
+    ) -> Result<()> {
+        let leaf = &args.leaf;
+        let verified = verify_listen_payout_claim(
+            &ctx.accounts.payout_window,
+            &ctx.accounts.authority_config,
+            &ctx.accounts.claimer.key(),
+            leaf,
+            &args.proof,
+            ctx.accounts.listen_payout_vault.amount,
+        )?;
+
+        let relayer_fee = args.relayer_fee;
         require!(
-            current == win.merkle_root,
-            ListenPayoutError::InvalidMerkleProof
+            (relayer_fee as u128) * 10_000
+                <= (leaf.amount_ccm as u128) * (MAX_RELAYER_FEE_BPS as u128),
+            ListenPayoutError::RelayerFeeTooHigh
         );
-        require!(leaf.amount_ccm > 0, ListenPayoutError::ZeroAmountClaim);
-
-        // Per audit finding H-01: enforce `total_amount_ccm` as a hard cap on
-        // actual on-chain settlement, not just an advisory field. Without this
-        // check, a publisher could declare `total_amount_ccm = 1` and commit a
-        // root with leaves summing to the full vault balance, draining the
-        // vault despite the per-window cap.
-        let new_claimed = win
-            .claimed_so_far
-            .checked_add(leaf.amount_ccm)
+        let delivered_amount = leaf
+            .amount_ccm
+            .checked_sub(relayer_fee)
             .ok_or(RailsError::MathOverflow)?;
-        require!(
-            new_claimed <= win.total_amount_ccm,
-            ListenPayoutError::ExceedsWindowTotal
-        );
-        win.claimed_so_far = new_claimed;
-
-        win.claim_bitmap[byte_idx] |= bit_mask;
 
         let bump = ctx.accounts.vault_config.vault_authority_bump;
         let signer_seeds: &[&[&[u8]]] = &[&[LISTEN_PAYOUT_VAULT_AUTHORITY_SEED, &[bump]]];
+        if relayer_fee > 0 {
+            token_interface::transfer_checked(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    TransferChecked {
+                        from: ctx.accounts.listen_payout_vault.to_account_info(),
+                        mint: ctx.accounts.ccm_mint.to_account_info(),
+                        to: ctx.accounts.relayer_ata.to_account_info(),
+                        authority: ctx.accounts.vault_authority.to_account_info(),
+                    },
+                    signer_seeds,
+                ),
+                relayer_fee,
+                ctx.accounts.ccm_mint.decimals,
+            )?;
+        }
         token_interface::transfer_checked(
             CpiContext::new_with_signer(
                 ctx.accounts.token_program.to_account_info(),
@@ -1160,10 +1383,13 @@ pub mod wzrd_rails {
                 },
                 signer_seeds,
             ),
-            leaf.amount_ccm,
+            delivered_amount,
             ctx.accounts.ccm_mint.decimals,
         )?;
 
+        verified.consume(&mut ctx.accounts.payout_window);
+
+        let slot = Clock::get()?.slot;
         emit!(ListenPayoutClaimed {
             window_id: leaf.window_id,
             leaf_index: leaf.leaf_index,
@@ -1171,13 +1397,132 @@ pub mod wzrd_rails {
             amount_ccm: leaf.amount_ccm,
             pool_id: leaf.pool_id,
             allocation_id: leaf.allocation_id,
-            claimed_at_slot: Clock::get()?.slot,
+            claimed_at_slot: slot,
+            recipient: ctx.accounts.claimer.key(),
+            leaf_hash: verified.leaf_hash,
+        });
+        emit!(RelayedClaim {
+            window_id: leaf.window_id,
+            leaf_index: leaf.leaf_index,
+            wallet: ctx.accounts.claimer.key(),
+            relayer: ctx.accounts.payer.key(),
+            relayer_fee,
+            delivered_amount,
+            slot,
         });
 
         Ok(())
     }
 }
 
+/// A listen payout leaf that passed every claim check but is not yet marked
+/// claimed. `consume` writes the anti-replay bit and the window total; callers
+/// run it after their transfers succeed.
+struct VerifiedListenPayoutClaim {
+    byte_idx: usize,
+    bit_mask: u8,
+    new_claimed: u64,
+    leaf_hash: [u8; 32],
+}
+
+impl VerifiedListenPayoutClaim {
+    fn consume(&self, win: &mut PayoutWindow) {
+        win.claimed_so_far = self.new_claimed;
+        win.claim_bitmap[self.byte_idx] |= self.bit_mask;
+    }
+}
+
+/// Checks shared by every listen payout claim path: pause flag, leaf/window
+/// binding, claimer binding, anti-replay bit, merkle proof, the per-window
+/// total (H-01) and the vault balance.
+fn verify_listen_payout_claim(
+    win: &PayoutWindow,
+    auth_cfg: &PayoutAuthorityConfig,
+    claimer: &Pubkey,
+    leaf: &PayoutAllocationLeafV1,
+    proof: &[[u8; 32]],
+    vault_balance: u64,
+) -> Result<VerifiedListenPayoutClaim> {
+    require!(!auth_cfg.paused, ListenPayoutError::Paused);
+    require!(
+        leaf.window_id == win.window_id,
+        ListenPayoutError::LeafWindowMismatch
+    );
+    require!(
+        leaf.schema_version == win.schema_version,
+        ListenPayoutError::SchemaVersionMismatch
+    );
+    require!(
+        leaf.schema_version == LISTEN_PAYOUT_LEAF_SCHEMA_V1,
+        ListenPayoutError::SchemaVersionMismatch
+    );
+    require!(
+        *claimer == leaf.wallet_pubkey,
+        ListenPayoutError::ClaimerWalletMismatch
+    );
+    require!(
+        leaf.leaf_index < win.leaf_count,
+        ListenPayoutError::LeafIndexOutOfBounds
+    );
+
+    let byte_idx = (leaf.leaf_index as usize) / 8;
+    let bit_idx = (leaf.leaf_index as usize) % 8;
+    require!(
+        byte_idx < win.claim_bitmap.len(),
+        ListenPayoutError::LeafIndexOutOfBounds
+    );
+    let bit_mask = 1u8 << bit_idx;
+    require!(
+        win.claim_bitmap[byte_idx] & bit_mask == 0,
+        ListenPayoutError::AlreadyClaimed
+    );
+    require!(
+        proof.len() <= MAX_PROOF_LEN,
+        ListenPayoutError::ProofTooLong
+    );
+
+    let leaf_hash = leaf.hash();
+    let mut current = leaf_hash;
+    for sibling in proof.iter() {
+        current = listen_payout_node_hash_v1(&current, sibling);
+    }
+    require!(
+        current == win.merkle_root,
+        ListenPayoutError::InvalidMerkleProof
+    );
+    require!(leaf.amount_ccm > 0, ListenPayoutError::ZeroAmountClaim);
+
+    // Per audit finding H-01: enforce `total_amount_ccm` as a hard cap on
+    // actual on-chain settlement, not just an advisory field. Without this
+    // check, a publisher could declare `total_amount_ccm = 1` and commit a
+    // root with leaves summing to the full vault balance, draining the
+    // vault despite the per-window cap.
+    let new_claimed = win
+        .claimed_so_far
+        .checked_add(leaf.amount_ccm)
+        .ok_or(RailsError::MathOverflow)?;
+    require!(
+        new_claimed <= win.total_amount_ccm,
+        ListenPayoutError::ExceedsWindowTotal
+    );
+
+    // Surface an underfunded vault as a typed error before the claim bit
+    // is touched, instead of an opaque Token-2022 insufficient-funds error
+    // from inside the CPI. The leaf stays claimable once the vault is
+    // topped up.
+    require!(
+        vault_balance >= leaf.amount_ccm,
+        ListenPayoutError::VaultInsufficient
+    );
+
+    Ok(VerifiedListenPayoutClaim {
+        byte_idx,
+        bit_mask,
+        new_claimed,
+        leaf_hash,
+    })
+}
+
 fn compensation_leaf(user: &Pubkey, amount: u64) -> [u8; 32] {
     keccak::hashv(&[
         COMPENSATION_LEAF_DOMAIN,
@@ -1352,7 +1697,7 @@ pub struct InitializePool<'info> {
     pub rent: Sysvar<'info, Rent>,
 }
 
-/// Shared admin-gated context for config-only mutations (set_admin).
+/// Shared admin-gated context for config-only mutations (emergency `set_admin`).
 /// Does NOT include a system_program because no account is initialized here.
 #[derive(Accounts)]
 pub struct AdminOnly<'info> {
@@ -1366,6 +1711,98 @@ pub struct AdminOnly<'info> {
     pub admin: Signer<'info>,
 }
 
+#[derive(Accounts)]
+pub struct ProposeAdmin<'info> {
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        has_one = admin @ RailsError::Unauthorized
+    )]
+    pub config: Account<'info, Config>,
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    /// `init_if_needed` so a pending proposal surfaces as `ProposalPending`
+    /// rather than the system program's "account already in use".
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = AdminProposal::LEN,
+        seeds = [ADMIN_PROPOSAL_SEED],
+        bump
+    )]
+    pub proposal: Account<'info, AdminProposal>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct AcceptAdmin<'info> {
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+    pub pending_admin: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [ADMIN_PROPOSAL_SEED],
+        bump = proposal.bump,
+        has_one = pending_admin @ RailsError::Unauthorized,
+        close = outgoing_admin
+    )]
+    pub proposal: Account<'info, AdminProposal>,
+    /// CHECK: rent refund target for the closed proposal; pinned to config.admin.
+    #[account(mut, address = config.admin @ RailsError::Unauthorized)]
+    pub outgoing_admin: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CancelAdminProposal<'info> {
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        has_one = admin @ RailsError::Unauthorized
+    )]
+    pub config: Account<'info, Config>,
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [ADMIN_PROPOSAL_SEED],
+        bump = proposal.bump,
+        close = admin
+    )]
+    pub proposal: Account<'info, AdminProposal>,
+}
+
+#[derive(Accounts)]
+pub struct SetTreasury<'info> {
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        has_one = admin @ RailsError::Unauthorized
+    )]
+    pub config: Account<'info, Config>,
+    pub admin: Signer<'info>,
+    #[account(
+        address = config.ccm_mint @ RailsError::InvalidMint,
+        mint::token_program = token_program,
+    )]
+    pub ccm_mint: Box<InterfaceAccount<'info, MintInterface>>,
+    /// CHECK: wallet that owns the treasury ATA; only used to derive the
+    /// canonical ATA address below.
+    pub treasury_owner: UncheckedAccount<'info>,
+    #[account(
+        associated_token::mint = ccm_mint,
+        associated_token::authority = treasury_owner,
+        associated_token::token_program = token_program,
+    )]
+    pub treasury: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(address = TOKEN_2022_PROGRAM_ID @ RailsError::InvalidTokenProgram)]
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
 #[derive(Accounts)]
 pub struct InitPayoutAuthorityConfig<'info> {
     #[account(
@@ -1564,6 +2001,38 @@ pub struct CompensateExternalStakers<'info> {
     pub rent: Sysvar<'info, Rent>,
 }
 
+#[derive(Accounts)]
+pub struct RescueTokens<'info> {
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        has_one = admin @ RailsError::Unauthorized
+    )]
+    pub config: Account<'info, Config>,
+    pub admin: Signer<'info>,
+    /// Foreign mint being rescued. Never the protocol CCM mint.
+    #[account(
+        constraint = mint.key() != config.ccm_mint @ RailsError::RescueProtocolMint,
+        mint::token_program = token_program,
+    )]
+    pub mint: Box<InterfaceAccount<'info, MintInterface>>,
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = config,
+        token::token_program = token_program,
+    )]
+    pub source: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        token::mint = mint,
+        token::token_program = token_program,
+    )]
+    pub destination: Box<InterfaceAccount<'info, TokenAccount>>,
+    /// SPL Token or Token-2022 — stuck mints may live under either program.
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
 #[derive(Accounts)]
 #[instruction(pool_id: u32)]
 pub struct SetRewardRate<'info> {
@@ -1918,3 +2387,121 @@ pub struct ClaimListenPayout<'info> {
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
 }
+
+#[derive(Accounts)]
+#[instruction(args: ClaimListenPayoutArgs)]
+pub struct ClaimListenPayoutTo<'info> {
+    #[account(mut)]
+    pub claimer: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [LISTEN_PAYOUT_WINDOW_SEED, &args.leaf.window_id.to_le_bytes()],
+        bump = payout_window.bump,
+    )]
+    pub payout_window: Account<'info, PayoutWindow>,
+    #[account(
+        seeds = [LISTEN_PAYOUT_AUTHORITY_CONFIG_SEED],
+        bump = authority_config.bump,
+    )]
+    pub authority_config: Account<'info, PayoutAuthorityConfig>,
+    #[account(
+        seeds = [LISTEN_PAYOUT_VAULT_CONFIG_SEED],
+        bump = vault_config.bump,
+    )]
+    pub vault_config: Account<'info, PayoutVaultConfig>,
+    #[account(
+        address = vault_config.ccm_mint,
+        mint::token_program = token_program,
+    )]
+    pub ccm_mint: Box<InterfaceAccount<'info, MintInterface>>,
+    #[account(
+        mut,
+        associated_token::mint = ccm_mint,
+        associated_token::authority = vault_authority,
+        associated_token::token_program = token_program,
+    )]
+    pub listen_payout_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    /// CHECK: PDA-only token authority, validated by seeds and bump.
+    #[account(
+        seeds = [LISTEN_PAYOUT_VAULT_AUTHORITY_SEED],
+        bump = vault_config.vault_authority_bump,
+    )]
+    pub vault_authority: UncheckedAccount<'info>,
+    /// CHECK: Delivery wallet; only used as the authority of `recipient_ata`.
+    pub recipient: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer = claimer,
+        associated_token::mint = ccm_mint,
+        associated_token::authority = recipient,
+        associated_token::token_program = token_program,
+    )]
+    pub recipient_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(address = TOKEN_2022_PROGRAM_ID @ RailsError::InvalidTokenProgram)]
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(args: ClaimListenPayoutRelayedArgs)]
+pub struct ClaimListenPayoutRelayed<'info> {
+    /// Relayer: pays the transaction and any ATA rent, receives `relayer_fee`.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub claimer: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [LISTEN_PAYOUT_WINDOW_SEED, &args.leaf.window_id.to_le_bytes()],
+        bump = payout_window.bump,
+    )]
+    pub payout_window: Account<'info, PayoutWindow>,
+    #[account(
+        seeds = [LISTEN_PAYOUT_AUTHORITY_CONFIG_SEED],
+        bump = authority_config.bump,
+    )]
+    pub authority_config: Account<'info, PayoutAuthorityConfig>,
+    #[account(
+        seeds = [LISTEN_PAYOUT_VAULT_CONFIG_SEED],
+        bump = vault_config.bump,
+    )]
+    pub vault_config: Account<'info, PayoutVaultConfig>,
+    #[account(
+        address = vault_config.ccm_mint,
+        mint::token_program = token_program,
+    )]
+    pub ccm_mint: Box<InterfaceAccount<'info, MintInterface>>,
+    #[account(
+        mut,
+        associated_token::mint = ccm_mint,
+        associated_token::authority = vault_authority,
+        associated_token::token_program = token_program,
+    )]
+    pub listen_payout_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    /// CHECK: PDA-only token authority, validated by seeds and bump.
+    #[account(
+        seeds = [LISTEN_PAYOUT_VAULT_AUTHORITY_SEED],
+        bump = vault_config.vault_authority_bump,
+    )]
+    pub vault_authority: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = ccm_mint,
+        associated_token::authority = claimer,
+        associated_token::token_program = token_program,
+    )]
+    pub claimer_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        constraint = relayer_ata.owner == payer.key()
+            @ ListenPayoutError::RelayerAccountMismatch,
+        constraint = relayer_ata.mint == ccm_mint.key()
+            @ ListenPayoutError::RelayerAccountMismatch,
+    )]
+    pub relayer_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(address = TOKEN_2022_PROGRAM_ID @ RailsError::InvalidTokenProgram)]
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
@@ -16,6 +16,7 @@ pub const STAKE_VAULT_SEED: &[u8] = b"stake_vault";
 pub const REWARD_VAULT_SEED: &[u8] = b"reward_vault";
 pub const COMP_VAULT_SEED: &[u8] = b"comp_vault";
 pub const COMP_CLAIMED_SEED: &[u8] = b"comp_claimed";
+pub const ADMIN_PROPOSAL_SEED: &[u8] = b"admin_proposal";
 pub const COMPENSATION_LEAF_DOMAIN: &[u8] = b"wzrd-rails-comp";
 pub const LISTEN_PAYOUT_AUTHORITY_CONFIG_SEED: &[u8] = b"listen_payout_authority_config";
 pub const LISTEN_PAYOUT_CAP_CONFIG_SEED: &[u8] = b"listen_payout_cap_config";
@@ -27,6 +28,9 @@ pub const OG_GNG_ATTENTION_ORACLE_PROGRAM: Pubkey =
     pubkey!("GnGzNdsQMxMpJfMeqnkGPsvHm8kwaDidiKjNU2dCVZop");
 pub const MAX_LEAVES_PER_WINDOW: u32 = 32_768;
 pub const MAX_PROOF_LEN: usize = 16;
+/// Ceiling on the relayer fee for gasless listen payout claims, in bps of the
+/// leaf amount. A relayer can never take more than 5% of a claim.
+pub const MAX_RELAYER_FEE_BPS: u64 = 500;
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
 pub struct PublishListenPayoutRootArgs {
@@ -43,6 +47,15 @@ pub struct ClaimListenPayoutArgs {
     pub proof: Vec<[u8; 32]>,
 }
 
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct ClaimListenPayoutRelayedArgs {
+    pub leaf: PayoutAllocationLeafV1,
+    pub proof: Vec<[u8; 32]>,
+    /// CCM routed to the relayer's ATA out of `leaf.amount_ccm`. Capped at
+    /// `MAX_RELAYER_FEE_BPS`.
+    pub relayer_fee: u64,
+}
+
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
 pub struct InitPayoutAuthorityConfigArgs {
     pub admin: Pubkey,
@@ -288,6 +301,23 @@ pub struct ListenPayoutClaimed {
     pub pool_id: [u8; 32],
     pub allocation_id: [u8; 16],
     pub claimed_at_slot: u64,
+    /// Wallet whose ATA received the CCM: the claimer, except for
+    /// `claim_listen_payout_to`. Appended so older decoders read a prefix.
+    pub recipient: Pubkey,
+    /// `leaf.hash()` of the claimed leaf, so indexers can mark it claimed in
+    /// their off-chain leaf set without re-deriving it from token transfers.
+    pub leaf_hash: [u8; 32],
+}
+
+#[event]
+pub struct RelayedClaim {
+    pub window_id: u64,
+    pub leaf_index: u32,
+    pub wallet: Pubkey,
+    pub relayer: Pubkey,
+    pub relayer_fee: u64,
+    pub delivered_amount: u64,
+    pub slot: u64,
 }
 
 #[event]
@@ -356,7 +386,7 @@ pub const MAX_LOCK_DURATION_SLOTS: u64 = 19_440_000;
 #[account]
 #[derive(Debug)]
 pub struct Config {
-    /// Admin authority. Can call set_admin, set_reward_rate,
+    /// Admin authority. Can call propose_admin, set_reward_rate,
     /// compensate_external_stakers, and initialize_pool. Should be a
     /// Squads V4 vault PDA for production; can be any signer for devnet/tests.
     pub admin: Pubkey,
@@ -407,6 +437,47 @@ pub struct AdminChanged {
     pub slot: u64,
 }
 
+/// Pending two-step admin handover. Created by `propose_admin`, closed by
+/// `accept_admin` (signed by `pending_admin`) or `cancel_admin_proposal`.
+/// Kept out of `Config` so the deployed Config layout is unchanged.
+///
+/// PDA: `[ADMIN_PROPOSAL_SEED]`
+#[account]
+#[derive(Debug)]
+pub struct AdminProposal {
+    pub pending_admin: Pubkey,
+    pub proposed_at_slot: u64,
+    pub bump: u8,
+}
+
+impl AdminProposal {
+    /// 8 disc + 32 pending_admin + 8 proposed_at_slot + 1 bump
+    pub const LEN: usize = 8 + 32 + 8 + 1;
+}
+
+#[event]
+pub struct AdminProposed {
+    pub config: Pubkey,
+    pub current_admin: Pubkey,
+    pub pending_admin: Pubkey,
+    pub slot: u64,
+}
+
+#[event]
+pub struct AdminProposalCancelled {
+    pub config: Pubkey,
+    pub pending_admin: Pubkey,
+    pub slot: u64,
+}
+
+#[event]
+pub struct TreasuryUpdated {
+    pub config: Pubkey,
+    pub old_treasury: Pubkey,
+    pub new_treasury: Pubkey,
+    pub slot: u64,
+}
+
 #[event]
 pub struct RewardRateChanged {
     pub pool: Pubkey,
@@ -435,6 +506,17 @@ pub struct CompensationClaimedEvent {
     pub slot: u64,
 }
 
+#[event]
+pub struct TokensRescued {
+    pub config: Pubkey,
+    pub admin: Pubkey,
+    pub mint: Pubkey,
+    pub source: Pubkey,
+    pub destination: Pubkey,
+    pub amount: u64,
+    pub slot: u64,
+}
+
 /// Stake pool. One per (program_id, pool_id) tuple.
 ///
 /// Pool 0 is the global default for Day 1. Per-channel pools (pool_id > 0) are
@@ -36,17 +36,18 @@ use std::path::{Path, PathBuf};
 use wzrd_rails::{
     accounts as rail_accounts, instruction as rail_ix, listen_payout_node_hash_v1,
     state::{
-        ClaimListenPayoutArgs, CompensationClaimed, Config, InitPayoutAuthorityConfigArgs,
-        InitPayoutCapConfigArgs, InitPayoutVaultConfigArgs, ListenPayoutClaimed,
-        PayoutAdminRotated, PayoutAllowlistUpdated, PayoutAuthorityConfig, PayoutCapConfig,
-        PayoutCapUpdated, PayoutPauseChanged, PayoutVaultConfig, PayoutWindow,
-        PayoutWindowPublished, PoolReallocated, PublishListenPayoutRootArgs, SetPausedArgs,
-        SetPayoutAdminArgs, SetPayoutAuthorityAllowlistArgs, SetPerWindowCcmCapArgs, StakePool,
-        UserStake, COMPENSATION_LEAF_DOMAIN, COMP_CLAIMED_SEED, COMP_VAULT_SEED, CONFIG_SEED,
-        LISTEN_PAYOUT_AUTHORITY_CONFIG_SEED, LISTEN_PAYOUT_CAP_CONFIG_SEED,
-        LISTEN_PAYOUT_VAULT_AUTHORITY_SEED, LISTEN_PAYOUT_VAULT_CONFIG_SEED,
-        LISTEN_PAYOUT_WINDOW_SEED, MAX_LEAVES_PER_WINDOW, MAX_PER_WINDOW_CAP_CCM, MAX_PROOF_LEN,
-        MAX_REWARD_RATE_PER_SLOT, POOL_SEED, REWARD_VAULT_SEED, STAKE_VAULT_SEED, USER_STAKE_SEED,
+        AdminProposal, ClaimListenPayoutArgs, ClaimListenPayoutRelayedArgs, CompensationClaimed,
+        Config, InitPayoutAuthorityConfigArgs, InitPayoutCapConfigArgs, InitPayoutVaultConfigArgs,
+        ListenPayoutClaimed, PayoutAdminRotated, PayoutAllowlistUpdated, PayoutAuthorityConfig,
+        PayoutCapConfig, PayoutCapUpdated, PayoutPauseChanged, PayoutVaultConfig, PayoutWindow,
+        PayoutWindowPublished, PoolReallocated, PublishListenPayoutRootArgs, RelayedClaim,
+        SetPausedArgs, SetPayoutAdminArgs, SetPayoutAuthorityAllowlistArgs, SetPerWindowCcmCapArgs,
+        StakePool, TreasuryUpdated, UserStake, ADMIN_PROPOSAL_SEED, COMPENSATION_LEAF_DOMAIN,
+        COMP_CLAIMED_SEED, COMP_VAULT_SEED, CONFIG_SEED, LISTEN_PAYOUT_AUTHORITY_CONFIG_SEED,
+        LISTEN_PAYOUT_CAP_CONFIG_SEED, LISTEN_PAYOUT_VAULT_AUTHORITY_SEED,
+        LISTEN_PAYOUT_VAULT_CONFIG_SEED, LISTEN_PAYOUT_WINDOW_SEED, MAX_LEAVES_PER_WINDOW,
+        MAX_PER_WINDOW_CAP_CCM, MAX_PROOF_LEN, MAX_RELAYER_FEE_BPS, MAX_REWARD_RATE_PER_SLOT,
+        POOL_SEED, REWARD_VAULT_SEED, STAKE_VAULT_SEED, USER_STAKE_SEED,
     },
     ListenPayoutError, PayoutAllocationLeafV1, RailsError, ID as WZRD_RAILS_PROGRAM_ID,
     LISTEN_PAYOUT_LEAF_SCHEMA_V1,
@@ -1187,6 +1188,107 @@ fn build_claim_compensation_ix(
     }
 }
 
+fn derive_admin_proposal() -> (LegacyPubkey, u8) {
+    LegacyPubkey::find_program_address(&[ADMIN_PROPOSAL_SEED], &WZRD_RAILS_PROGRAM_ID)
+}
+
+fn build_propose_admin_ix(
+    config: LegacyPubkey,
+    admin: LegacyPubkey,
+    new_admin: LegacyPubkey,
+) -> LegacyInstruction {
+    LegacyInstruction {
+        program_id: WZRD_RAILS_PROGRAM_ID,
+        accounts: rail_accounts::ProposeAdmin {
+            config,
+            admin,
+            proposal: derive_admin_proposal().0,
+            system_program: system_program::ID,
+        }
+        .to_account_metas(None),
+        data: rail_ix::ProposeAdmin {
+            new_admin: Pubkey::new_from_array(new_admin.to_bytes()),
+        }
+        .data(),
+    }
+}
+
+fn build_accept_admin_ix(
+    config: LegacyPubkey,
+    pending_admin: LegacyPubkey,
+    outgoing_admin: LegacyPubkey,
+) -> LegacyInstruction {
+    LegacyInstruction {
+        program_id: WZRD_RAILS_PROGRAM_ID,
+        accounts: rail_accounts::AcceptAdmin {
+            config,
+            pending_admin,
+            proposal: derive_admin_proposal().0,
+            outgoing_admin,
+        }
+        .to_account_metas(None),
+        data: rail_ix::AcceptAdmin {}.data(),
+    }
+}
+
+fn build_cancel_admin_proposal_ix(config: LegacyPubkey, admin: LegacyPubkey) -> LegacyInstruction {
+    LegacyInstruction {
+        program_id: WZRD_RAILS_PROGRAM_ID,
+        accounts: rail_accounts::CancelAdminProposal {
+            config,
+            admin,
+            proposal: derive_admin_proposal().0,
+        }
+        .to_account_metas(None),
+        data: rail_ix::CancelAdminProposal {}.data(),
+    }
+}
+
+fn build_set_treasury_ix(
+    config: LegacyPubkey,
+    admin: LegacyPubkey,
+    ccm_mint: LegacyPubkey,
+    treasury_owner: LegacyPubkey,
+    treasury: LegacyPubkey,
+) -> LegacyInstruction {
+    LegacyInstruction {
+        program_id: WZRD_RAILS_PROGRAM_ID,
+        accounts: rail_accounts::SetTreasury {
+            config,
+            admin,
+            ccm_mint,
+            treasury_owner,
+            treasury,
+            token_program: spl_token_2022::id(),
+        }
+        .to_account_metas(None),
+        data: rail_ix::SetTreasury {}.data(),
+    }
+}
+
+fn build_rescue_tokens_ix(
+    config: LegacyPubkey,
+    admin: LegacyPubkey,
+    mint: LegacyPubkey,
+    source: LegacyPubkey,
+    destination: LegacyPubkey,
+    amount: u64,
+) -> LegacyInstruction {
+    LegacyInstruction {
+        program_id: WZRD_RAILS_PROGRAM_ID,
+        accounts: rail_accounts::RescueTokens {
+            config,
+            admin,
+            mint,
+            source,
+            destination,
+            token_program: spl_token_2022::id(),
+        }
+        .to_account_metas(None),
+        data: rail_ix::RescueTokens { amount }.data(),
+    }
+}
+
 fn build_publish_listen_payout_root_ix(
     authority: LegacyPubkey,
     authority_config: LegacyPubkey,
@@ -1368,6 +1470,76 @@ fn build_claim_listen_payout_ix(
     }
 }
 
+#[allow(clippy::too_many_arguments)]
+fn build_claim_listen_payout_to_ix(
+    claimer: LegacyPubkey,
+    authority_config: LegacyPubkey,
+    vault_config: LegacyPubkey,
+    ccm_mint: LegacyPubkey,
+    listen_payout_vault: LegacyPubkey,
+    vault_authority: LegacyPubkey,
+    recipient: LegacyPubkey,
+    recipient_ata: LegacyPubkey,
+    args: ClaimListenPayoutArgs,
+) -> LegacyInstruction {
+    let payout_window = derive_payout_window(args.leaf.window_id).0;
+    LegacyInstruction {
+        program_id: WZRD_RAILS_PROGRAM_ID,
+        accounts: rail_accounts::ClaimListenPayoutTo {
+            claimer,
+            payout_window,
+            authority_config,
+            vault_config,
+            ccm_mint,
+            listen_payout_vault,
+            vault_authority,
+            recipient,
+            recipient_ata,
+            token_program: spl_token_2022::id(),
+            associated_token_program: ASSOCIATED_TOKEN_PROGRAM_ID,
+            system_program: system_program::ID,
+        }
+        .to_account_metas(None),
+        data: rail_ix::ClaimListenPayoutTo { args }.data(),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn build_claim_listen_payout_relayed_ix(
+    payer: LegacyPubkey,
+    claimer: LegacyPubkey,
+    authority_config: LegacyPubkey,
+    vault_config: LegacyPubkey,
+    ccm_mint: LegacyPubkey,
+    listen_payout_vault: LegacyPubkey,
+    vault_authority: LegacyPubkey,
+    claimer_ata: LegacyPubkey,
+    relayer_ata: LegacyPubkey,
+    args: ClaimListenPayoutRelayedArgs,
+) -> LegacyInstruction {
+    let payout_window = derive_payout_window(args.leaf.window_id).0;
+    LegacyInstruction {
+        program_id: WZRD_RAILS_PROGRAM_ID,
+        accounts: rail_accounts::ClaimListenPayoutRelayed {
+            payer,
+            claimer,
+            payout_window,
+            authority_config,
+            vault_config,
+            ccm_mint,
+            listen_payout_vault,
+            vault_authority,
+            claimer_ata,
+            relayer_ata,
+            token_program: spl_token_2022::id(),
+            associated_token_program: ASSOCIATED_TOKEN_PROGRAM_ID,
+            system_program: system_program::ID,
+        }
+        .to_account_metas(None),
+        data: rail_ix::ClaimListenPayoutRelayed { args }.data(),
+    }
+}
+
 fn build_direct_token_transfer_ix(
     authority: LegacyPubkey,
     from: LegacyPubkey,
@@ -2153,6 +2325,7 @@ fn claim_listen_payout_happy_path_creates_ata_and_transfers() {
     assert_eq!(event.amount_ccm, amount);
     assert_eq!(event.pool_id, tree.leaves[0].pool_id);
     assert_eq!(event.allocation_id, tree.leaves[0].allocation_id);
+    assert_eq!(event.leaf_hash, tree.leaves[0].hash());
     assert_eq!(event.claimed_at_slot, 789);
 }
 
@@ -2270,6 +2443,209 @@ fn claim_listen_payout_vault_underfunded_reverts_without_bitmap() {
     let tree = build_listen_payout_tree(&wallets, &amounts);
     publish_tree(&mut env, &tree);
 
+    assert_listen_payout_error(
+        env.try_claim_listen_payout_user_a(claim_args(&tree, 0)),
+        ListenPayoutError::VaultInsufficient,
+    );
+
+    let win: PayoutWindow =
+        read_anchor_account(&env.svm, &derive_payout_window(PAYOUT_WINDOW_ID).0);
+    assert_eq!(win.claim_bitmap[0] & 0b0000_0001, 0);
+    assert_eq!(win.claimed_so_far, 0);
+
+    // Topping the vault up makes the same leaf claimable.
+    let ccm_mint = env.ccm_mint_pubkey();
+    let vault = env.listen_payout_vault;
+    mint_token_2022(&mut env.svm, &env.admin, &ccm_mint, &vault, 1);
+    env.svm.expire_blockhash();
+    env.claim_listen_payout_user_a(claim_args(&tree, 0));
+
+    let win: PayoutWindow =
+        read_anchor_account(&env.svm, &derive_payout_window(PAYOUT_WINDOW_ID).0);
+    assert_eq!(win.claim_bitmap[0] & 0b0000_0001, 0b0000_0001);
+    assert_eq!(read_token_balance(&env.svm, &vault), 0);
+}
+
+#[test]
+fn claim_listen_payout_to_delivers_to_third_party_recipient() {
+    let mut env = setup_rails();
+    let (tree, _, _, _) = setup_published_claim_tree(&mut env);
+    let ccm_mint = env.ccm_mint_pubkey();
+    let user_a = env.user_a.pubkey();
+    let recipient = Keypair::new();
+    let recipient_pk = legacy_from_signer(&recipient);
+    let recipient_ata = derive_ata(&recipient_pk, &ccm_mint);
+
+    let ix = build_claim_listen_payout_to_ix(
+        user_a,
+        env.payout_authority_config,
+        env.payout_vault_config,
+        ccm_mint,
+        env.listen_payout_vault,
+        env.payout_vault_authority,
+        recipient_pk,
+        recipient_ata,
+        claim_args(&tree, 0),
+    );
+    let meta = send_tx_with_metadata(&mut env.svm, &[&env.user_a.signer], &[ix]);
+
+    assert_eq!(
+        read_token_balance(&env.svm, &recipient_ata),
+        tree.leaves[0].amount_ccm
+    );
+    assert!(env
+        .svm
+        .get_account(&address_from_legacy(&derive_ata(&user_a, &ccm_mint)))
+        .is_none());
+    let event: ListenPayoutClaimed = decode_anchor_event(&meta.logs);
+    assert_eq!(event.wallet, user_a);
+    assert_eq!(event.recipient, recipient_pk);
+}
+
+#[test]
+fn claim_listen_payout_to_still_binds_proof_to_claimer() {
+    let mut env = setup_rails();
+    let (tree, user_b, _, _) = setup_published_claim_tree(&mut env);
+    let ccm_mint = env.ccm_mint_pubkey();
+    let user_b_pk = user_b.pubkey();
+
+    // user_b cannot redirect user_a's leaf, not even to itself.
+    let ix = build_claim_listen_payout_to_ix(
+        user_b_pk,
+        env.payout_authority_config,
+        env.payout_vault_config,
+        ccm_mint,
+        env.listen_payout_vault,
+        env.payout_vault_authority,
+        user_b_pk,
+        derive_ata(&user_b_pk, &ccm_mint),
+        claim_args(&tree, 0),
+    );
+    assert_listen_payout_error(
+        try_send_tx_with_metadata(&mut env.svm, &[&user_b.signer], &[ix]),
+        ListenPayoutError::ClaimerWalletMismatch,
+    );
+}
+
+/// Builds a relayed claim of leaf 0 for user_a, paying the relayer fee into
+/// `relayer_ata`. Returns the relayer and the instruction; the relayer is the
+/// payer and must sign first so it also pays the transaction fee.
+fn relayed_claim_user_a(
+    env: &mut TestEnv,
+    tree: &ListenPayoutTree,
+    relayer_fee: u64,
+    relayer_ata_owner: Option<LegacyPubkey>,
+) -> (Keypair, LegacyPubkey, LegacyInstruction) {
+    let ccm_mint = env.ccm_mint_pubkey();
+    let relayer = Keypair::new();
+    let relayer_pk = legacy_from_signer(&relayer);
+    env.svm
+        .airdrop(&address_from_legacy(&relayer_pk), 1_000_000_000)
+        .expect("airdrop relayer");
+    let relayer_ata = create_associated_token_2022_account(
+        &mut env.svm,
+        &env.admin,
+        &relayer_ata_owner.unwrap_or(relayer_pk),
+        &ccm_mint,
+    );
+    let user_a = env.user_a.pubkey();
+    let claim = claim_args(tree, 0);
+    let ix = build_claim_listen_payout_relayed_ix(
+        relayer_pk,
+        user_a,
+        env.payout_authority_config,
+        env.payout_vault_config,
+        ccm_mint,
+        env.listen_payout_vault,
+        env.payout_vault_authority,
+        derive_ata(&user_a, &ccm_mint),
+        relayer_ata,
+        ClaimListenPayoutRelayedArgs {
+            leaf: claim.leaf,
+            proof: claim.proof,
+            relayer_fee,
+        },
+    );
+    (relayer, relayer_ata, ix)
+}
+
+#[test]
+fn claim_listen_payout_relayed_splits_fee_and_relayer_pays() {
+    let mut env = setup_rails();
+    let (tree, _, _, _) = setup_published_claim_tree(&mut env);
+    let amount = tree.leaves[0].amount_ccm;
+    let fee = amount * MAX_RELAYER_FEE_BPS / 10_000;
+    let (relayer, relayer_ata, ix) = relayed_claim_user_a(&mut env, &tree, fee, None);
+    let claimer_ata = derive_ata(&env.user_a.pubkey(), &env.ccm_mint_pubkey());
+    assert!(env
+        .svm
+        .get_account(&address_from_legacy(&claimer_ata))
+        .is_none());
+    let user_a_address = address_from_legacy(&env.user_a.pubkey());
+    let claimer_lamports_before = env.svm.get_balance(&user_a_address).unwrap_or(0);
+
+    let meta = send_tx_with_metadata(&mut env.svm, &[&relayer, &env.user_a.signer], &[ix]);
+
+    assert_eq!(read_token_balance(&env.svm, &relayer_ata), fee);
+    assert_eq!(read_token_balance(&env.svm, &claimer_ata), amount - fee);
+    // Gasless: the relayer covered the tx fee and the new ATA's rent.
+    assert_eq!(
+        env.svm.get_balance(&user_a_address).unwrap_or(0),
+        claimer_lamports_before
+    );
+    let event: RelayedClaim = decode_anchor_event(&meta.logs);
+    assert_eq!(event.wallet, env.user_a.pubkey());
+    assert_eq!(event.relayer, legacy_from_signer(&relayer));
+    assert_eq!(event.relayer_fee, fee);
+    assert_eq!(event.delivered_amount, amount - fee);
+}
+
+#[test]
+fn claim_listen_payout_relayed_rejects_fee_above_cap() {
+    let mut env = setup_rails();
+    let (tree, _, _, _) = setup_published_claim_tree(&mut env);
+    let fee = tree.leaves[0].amount_ccm * MAX_RELAYER_FEE_BPS / 10_000 + 1;
+    let (relayer, relayer_ata, ix) = relayed_claim_user_a(&mut env, &tree, fee, None);
+
+    assert_listen_payout_error(
+        try_send_tx_with_metadata(&mut env.svm, &[&relayer, &env.user_a.signer], &[ix]),
+        ListenPayoutError::RelayerFeeTooHigh,
+    );
+    assert_eq!(read_token_balance(&env.svm, &relayer_ata), 0);
+}
+
+#[test]
+fn claim_listen_payout_relayed_rejects_foreign_relayer_ata() {
+    let mut env = setup_rails();
+    let (tree, user_b, _, _) = setup_published_claim_tree(&mut env);
+    let (relayer, _, ix) = relayed_claim_user_a(&mut env, &tree, 1, Some(user_b.pubkey()));
+
+    assert_listen_payout_error(
+        try_send_tx_with_metadata(&mut env.svm, &[&relayer, &env.user_a.signer], &[ix]),
+        ListenPayoutError::RelayerAccountMismatch,
+    );
+}
+
+/// Flips a Token-2022 account's `state` byte (offset 108) in place so tests
+/// can freeze a destination without a mint freeze authority.
+fn set_token_account_frozen(svm: &mut LiteSVM, token_account: &LegacyPubkey, frozen: bool) {
+    let address = address_from_legacy(token_account);
+    let mut account = svm.get_account(&address).expect("token account exists");
+    account.data[108] = if frozen { 2 } else { 1 };
+    svm.set_account(address, account)
+        .expect("set_account token account");
+}
+
+#[test]
+fn claim_listen_payout_failed_transfer_leaves_bitmap_clear() {
+    let mut env = setup_rails();
+    let (tree, _, _, _) = setup_published_claim_tree(&mut env);
+    let ccm_mint = env.ccm_mint_pubkey();
+    let user_a = env.user_a.pubkey();
+    let claimer_ata =
+        create_associated_token_2022_account(&mut env.svm, &env.admin, &user_a, &ccm_mint);
+    set_token_account_frozen(&mut env.svm, &claimer_ata, true);
+
     assert!(env
         .try_claim_listen_payout_user_a(claim_args(&tree, 0))
         .is_err());
@@ -2277,6 +2653,20 @@ fn claim_listen_payout_vault_underfunded_reverts_without_bitmap() {
     let win: PayoutWindow =
         read_anchor_account(&env.svm, &derive_payout_window(PAYOUT_WINDOW_ID).0);
     assert_eq!(win.claim_bitmap[0] & 0b0000_0001, 0);
+    assert_eq!(win.claimed_so_far, 0);
+
+    // Thawing the destination lets the same leaf be retried.
+    set_token_account_frozen(&mut env.svm, &claimer_ata, false);
+    env.svm.expire_blockhash();
+    env.claim_listen_payout_user_a(claim_args(&tree, 0));
+
+    let win: PayoutWindow =
+        read_anchor_account(&env.svm, &derive_payout_window(PAYOUT_WINDOW_ID).0);
+    assert_eq!(win.claim_bitmap[0] & 0b0000_0001, 0b0000_0001);
+    assert_eq!(
+        read_token_balance(&env.svm, &claimer_ata),
+        tree.leaves[0].amount_ccm
+    );
 }
 
 #[test]
@@ -2747,6 +3137,262 @@ fn test_claim_compensation_bad_signer_reverts() {
     );
 }
 
+#[test]
+fn test_two_step_admin_propose_and_accept() {
+    let mut env = setup_rails();
+    let old_admin = env.admin_pubkey();
+    let successor = Keypair::new();
+    let successor_pk = legacy_from_signer(&successor);
+    env.svm
+        .airdrop(&successor.pubkey(), 1_000_000_000)
+        .expect("airdrop successor");
+
+    let ix = build_propose_admin_ix(env.config, old_admin, successor_pk);
+    send_tx(&mut env.svm, &[&env.admin], &[ix]);
+    let config: Config = read_anchor_account(&env.svm, &env.config);
+    assert_eq!(config.admin, old_admin, "proposal alone must not rotate");
+    let proposal: AdminProposal = read_anchor_account(&env.svm, &derive_admin_proposal().0);
+    assert_eq!(proposal.pending_admin, successor_pk);
+
+    let ix = build_accept_admin_ix(env.config, successor_pk, old_admin);
+    send_tx(&mut env.svm, &[&successor], &[ix]);
+    let config: Config = read_anchor_account(&env.svm, &env.config);
+    assert_eq!(config.admin, successor_pk);
+    assert!(env
+        .svm
+        .get_account(&address_from_legacy(&derive_admin_proposal().0))
+        .is_none_or(|account| account.lamports == 0));
+}
+
+#[test]
+fn test_two_step_admin_accept_by_wrong_key_rejected() {
+    let mut env = setup_rails();
+    let old_admin = env.admin_pubkey();
+    let successor = legacy_from_signer(&Keypair::new());
+
+    let ix = build_propose_admin_ix(env.config, old_admin, successor);
+    send_tx(&mut env.svm, &[&env.admin], &[ix]);
+
+    let impostor = env.user_a.pubkey();
+    let ix = build_accept_admin_ix(env.config, impostor, old_admin);
+    assert_rails_error(
+        try_send_tx(&mut env.svm, &[&env.user_a.signer], &[ix]),
+        RailsError::Unauthorized,
+    );
+    let config: Config = read_anchor_account(&env.svm, &env.config);
+    assert_eq!(config.admin, old_admin);
+}
+
+#[test]
+fn test_two_step_admin_cancel_proposal() {
+    let mut env = setup_rails();
+    let old_admin = env.admin_pubkey();
+    let successor = Keypair::new();
+    let successor_pk = legacy_from_signer(&successor);
+    env.svm
+        .airdrop(&successor.pubkey(), 1_000_000_000)
+        .expect("airdrop successor");
+
+    let ix = build_propose_admin_ix(env.config, old_admin, successor_pk);
+    send_tx(&mut env.svm, &[&env.admin], &[ix]);
+    let ix = build_cancel_admin_proposal_ix(env.config, old_admin);
+    send_tx(&mut env.svm, &[&env.admin], &[ix]);
+
+    let ix = build_accept_admin_ix(env.config, successor_pk, old_admin);
+    assert!(try_send_tx(&mut env.svm, &[&successor], &[ix]).is_err());
+    let config: Config = read_anchor_account(&env.svm, &env.config);
+    assert_eq!(config.admin, old_admin);
+}
+
+#[test]
+fn test_two_step_admin_second_proposal_rejected_while_pending() {
+    let mut env = setup_rails();
+    let old_admin = env.admin_pubkey();
+    let first = legacy_from_signer(&Keypair::new());
+    let second = legacy_from_signer(&Keypair::new());
+
+    let ix = build_propose_admin_ix(env.config, old_admin, first);
+    send_tx(&mut env.svm, &[&env.admin], &[ix]);
+    let ix = build_propose_admin_ix(env.config, old_admin, second);
+    assert_rails_error(
+        try_send_tx(&mut env.svm, &[&env.admin], &[ix]),
+        RailsError::ProposalPending,
+    );
+    let proposal: AdminProposal = read_anchor_account(&env.svm, &derive_admin_proposal().0);
+    assert_eq!(proposal.pending_admin, first);
+
+    // Once cancelled, a fresh proposal goes through.
+    let ix = build_cancel_admin_proposal_ix(env.config, old_admin);
+    send_tx(&mut env.svm, &[&env.admin], &[ix]);
+    let ix = build_propose_admin_ix(env.config, old_admin, second);
+    send_tx(&mut env.svm, &[&env.admin], &[ix]);
+    let proposal: AdminProposal = read_anchor_account(&env.svm, &derive_admin_proposal().0);
+    assert_eq!(proposal.pending_admin, second);
+}
+
+#[test]
+fn test_set_treasury_rotates_to_ccm_account() {
+    let mut env = setup_rails();
+    let ccm_mint = env.ccm_mint_pubkey();
+    let treasury_owner = legacy_from_signer(&Keypair::new());
+    let new_treasury =
+        create_associated_token_2022_account(&mut env.svm, &env.admin, &treasury_owner, &ccm_mint);
+
+    let ix = build_set_treasury_ix(
+        env.config,
+        env.admin_pubkey(),
+        ccm_mint,
+        treasury_owner,
+        new_treasury,
+    );
+    let meta = send_tx_with_metadata(&mut env.svm, &[&env.admin], &[ix]);
+
+    let config: Config = read_anchor_account(&env.svm, &env.config);
+    assert_eq!(config.treasury_ccm_ata, new_treasury);
+    let event: TreasuryUpdated = decode_anchor_event(&meta.logs);
+    assert_eq!(event.new_treasury, new_treasury);
+}
+
+#[test]
+fn test_set_treasury_rejects_non_canonical_account() {
+    let mut env = setup_rails();
+    let admin_pk = env.admin_pubkey();
+    let ccm_mint = env.ccm_mint_pubkey();
+
+    // A keypair-addressed CCM account is a valid token account but not an ATA.
+    let ix = build_set_treasury_ix(env.config, admin_pk, ccm_mint, admin_pk, env.admin_ccm);
+    let failure = try_send_tx(&mut env.svm, &[&env.admin], &[ix])
+        .expect_err("non-canonical treasury must be rejected");
+    assert_eq!(
+        failure.err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(anchor_lang::error::ErrorCode::ConstraintAssociated as u32),
+        )
+    );
+}
+
+#[test]
+fn test_set_treasury_rejects_wrong_mint() {
+    let mut env = setup_rails();
+    let admin_pk = env.admin_pubkey();
+    let config_before: Config = read_anchor_account(&env.svm, &env.config);
+
+    let foreign_mint = Keypair::new();
+    create_plain_token_2022_mint(&mut env.svm, &env.admin, &foreign_mint, &admin_pk);
+    let foreign_mint_pk = legacy_from_signer(&foreign_mint);
+    let foreign_ata =
+        create_associated_token_2022_account(&mut env.svm, &env.admin, &admin_pk, &foreign_mint_pk);
+
+    let ix = build_set_treasury_ix(env.config, admin_pk, foreign_mint_pk, admin_pk, foreign_ata);
+    assert_rails_error(
+        try_send_tx(&mut env.svm, &[&env.admin], &[ix]),
+        RailsError::InvalidMint,
+    );
+    let config: Config = read_anchor_account(&env.svm, &env.config);
+    assert_eq!(config.treasury_ccm_ata, config_before.treasury_ccm_ata);
+}
+
+/// Creates a config-owned token account for `mint` holding `amount`, standing
+/// in for a wrong-mint airdrop that landed on a program PDA.
+fn seed_config_owned_balance(env: &mut TestEnv, mint: &LegacyPubkey, amount: u64) -> LegacyPubkey {
+    let stuck = Keypair::new();
+    let config = env.config;
+    create_token_2022_account(&mut env.svm, &env.admin, &stuck, mint, &config);
+    let stuck_pk = legacy_from_signer(&stuck);
+    mint_token_2022(&mut env.svm, &env.admin, mint, &stuck_pk, amount);
+    stuck_pk
+}
+
+#[test]
+fn test_rescue_tokens_moves_foreign_mint() {
+    let mut env = setup_rails();
+    let admin_pk = env.admin_pubkey();
+
+    let foreign_mint = Keypair::new();
+    create_plain_token_2022_mint(&mut env.svm, &env.admin, &foreign_mint, &admin_pk);
+    let foreign_mint_pk = legacy_from_signer(&foreign_mint);
+    let stuck = seed_config_owned_balance(&mut env, &foreign_mint_pk, 9_000);
+    let destination =
+        create_associated_token_2022_account(&mut env.svm, &env.admin, &admin_pk, &foreign_mint_pk);
+
+    let ix = build_rescue_tokens_ix(
+        env.config,
+        admin_pk,
+        foreign_mint_pk,
+        stuck,
+        destination,
+        9_000,
+    );
+    send_tx(&mut env.svm, &[&env.admin], &[ix]);
+
+    assert_eq!(read_token_balance(&env.svm, &stuck), 0);
+    assert_eq!(read_token_balance(&env.svm, &destination), 9_000);
+}
+
+#[test]
+fn test_rescue_tokens_rejects_ccm_mint() {
+    let mut env = setup_rails();
+    let admin_pk = env.admin_pubkey();
+    let ccm_mint = env.ccm_mint_pubkey();
+    let stuck = seed_config_owned_balance(&mut env, &ccm_mint, 5_000);
+
+    let ix = build_rescue_tokens_ix(env.config, admin_pk, ccm_mint, stuck, env.admin_ccm, 5_000);
+    assert_rails_error(
+        try_send_tx(&mut env.svm, &[&env.admin], &[ix]),
+        RailsError::RescueProtocolMint,
+    );
+    assert_eq!(read_token_balance(&env.svm, &stuck), 5_000);
+}
+
+#[test]
+fn test_rescue_tokens_rejects_zero_amount() {
+    let mut env = setup_rails();
+    let admin_pk = env.admin_pubkey();
+
+    let foreign_mint = Keypair::new();
+    create_plain_token_2022_mint(&mut env.svm, &env.admin, &foreign_mint, &admin_pk);
+    let foreign_mint_pk = legacy_from_signer(&foreign_mint);
+    let stuck = seed_config_owned_balance(&mut env, &foreign_mint_pk, 1_000);
+    let destination =
+        create_associated_token_2022_account(&mut env.svm, &env.admin, &admin_pk, &foreign_mint_pk);
+
+    let ix = build_rescue_tokens_ix(env.config, admin_pk, foreign_mint_pk, stuck, destination, 0);
+    assert_rails_error(
+        try_send_tx(&mut env.svm, &[&env.admin], &[ix]),
+        RailsError::RescueAmountZero,
+    );
+    assert_eq!(read_token_balance(&env.svm, &stuck), 1_000);
+}
+
+#[test]
+fn test_rescue_tokens_admin_only() {
+    let mut env = setup_rails();
+    let admin_pk = env.admin_pubkey();
+
+    let foreign_mint = Keypair::new();
+    create_plain_token_2022_mint(&mut env.svm, &env.admin, &foreign_mint, &admin_pk);
+    let foreign_mint_pk = legacy_from_signer(&foreign_mint);
+    let stuck = seed_config_owned_balance(&mut env, &foreign_mint_pk, 1_000);
+    let outsider = env.user_a.pubkey();
+    let destination =
+        create_associated_token_2022_account(&mut env.svm, &env.admin, &outsider, &foreign_mint_pk);
+
+    let ix = build_rescue_tokens_ix(
+        env.config,
+        outsider,
+        foreign_mint_pk,
+        stuck,
+        destination,
+        1_000,
+    );
+    assert_rails_error(
+        try_send_tx(&mut env.svm, &[&env.user_a.signer], &[ix]),
+        RailsError::Unauthorized,
+    );
+    assert_eq!(read_token_balance(&env.svm, &stuck), 1_000);
+}
+
 // Helpers for init-time error coverage. These mirror
 // `try_init_payout_authority_config_as_admin` but expose the cap_config and
 // vault_config init paths with controlled args so tests can drive specific